@@ -1,6 +1,41 @@
 // build.rs — robust liboqs discovery via LIBOQS_DIR or pkg-config
 
 use std::env;
+use std::path::{Path, PathBuf};
+
+/// `oqsconfig.h` defines mirrored as `cfg`s, so the crate knows how the
+/// linked liboqs was configured.
+const CONFIG_CFGS: &[(&str, &str)] = &[
+    ("OQS_DIST_BUILD", "oqs_dist_build"),
+    ("OQS_DIST_X86_64_BUILD", "oqs_dist_x86_64_build"),
+    ("OQS_USE_AVX2_INSTRUCTIONS", "oqs_use_avx2_instructions"),
+];
+
+/// Emits a `cfg` for every [`CONFIG_CFGS`] define set in the first
+/// `oqs/oqsconfig.h` found under `include_dirs`. No header, no cfgs.
+fn emit_config_cfgs(include_dirs: &[PathBuf]) {
+    for (_, cfg) in CONFIG_CFGS {
+        println!("cargo:rustc-check-cfg=cfg({cfg})");
+    }
+    let Some(header) = include_dirs
+        .iter()
+        .map(|dir| dir.join("oqs").join("oqsconfig.h"))
+        .find(|path| path.is_file())
+    else {
+        return;
+    };
+    println!("cargo:rerun-if-changed={}", header.display());
+    let text = std::fs::read_to_string(&header).unwrap_or_default();
+    for (define, cfg) in CONFIG_CFGS {
+        let set = text.lines().any(|line| {
+            let mut words = line.split_whitespace();
+            words.next() == Some("#define") && words.next() == Some(define)
+        });
+        if set {
+            println!("cargo:rustc-cfg={cfg}");
+        }
+    }
+}
 
 fn main() {
     println!("cargo:rerun-if-env-changed=LIBOQS_DIR");
@@ -16,6 +51,7 @@ fn main() {
             println!("cargo:rustc-link-search=native={}/lib", dir);
             println!("cargo:rustc-link-lib=dylib=oqs");
         }
+        emit_config_cfgs(&[Path::new(&dir).join("include")]);
         return;
     }
 
@@ -25,7 +61,7 @@ fn main() {
         .cargo_metadata(true)
         .probe("liboqs")
     {
-        Ok(_lib) => { /* Link flags already emitted */ }
+        Ok(lib) => emit_config_cfgs(&lib.include_paths), // link flags already emitted
         Err(e) if wants_liboqs => {
             panic!(
 "liboqs not found via pkg-config:
@@ -45,6 +81,6 @@ Then set one of:
 "
             );
         }
-        Err(_) => { /* mock build OK */ }
+        Err(_) => emit_config_cfgs(&[]), // mock build OK
     }
 }
@@ -1,7 +1,9 @@
+// Ad-hoc key derivation from a KEM secret. For a standard construction with
+// AEAD and exporter secrets, prefer `oqs_safe::hpke` (feature `hpke`).
+
 use hkdf::Hkdf;
 use oqs_safe::kem::{Kem, Kyber768};
 use sha2::Sha256;
-use subtle::ConstantTimeEq;
 
 fn hkdf(ss: &[u8], label: &[u8], out_len: usize) -> Vec<u8> {
     let hk = Hkdf::<Sha256>::new(Some(b"oqs-safe context"), ss);
@@ -15,13 +17,8 @@ fn main() {
     let (ct, ss_initiator) = Kyber768::encapsulate(&pk).expect("encaps");
     let ss_responder = Kyber768::decapsulate(&ct, &sk).expect("decaps");
 
-    assert_eq!(
-        ss_initiator
-            .as_bytes()
-            .ct_eq(ss_responder.as_bytes())
-            .unwrap_u8(),
-        1
-    );
+    // `SharedSecret` equality is constant time.
+    assert!(ss_initiator == ss_responder);
 
     let enc_key = hkdf(ss_initiator.as_bytes(), b"enc", 32);
     let mac_key = hkdf(ss_initiator.as_bytes(), b"mac", 32);
@@ -0,0 +1,50 @@
+//! Runtime information about the active backend.
+
+/// Which implementation the crate was built against.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Backend {
+    /// Real cryptography via the linked liboqs.
+    Liboqs,
+    /// Size-faithful random buffers; not cryptography.
+    Mock,
+}
+
+/// Snapshot returned by [`backend_info`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct BackendInfo {
+    pub backend: Backend,
+    /// `OQS_version()` of the linked liboqs (`None` for the mock backend).
+    pub liboqs_version: Option<&'static str>,
+    /// Whether liboqs' AVX2 code paths (e.g. BIKE's AVX2 decoder) are
+    /// compiled in and selected: on dist builds by runtime CPU detection,
+    /// otherwise by `OQS_USE_AVX2_INSTRUCTIONS` in the linked `oqsconfig.h`.
+    /// `false` if that header was not found at build time, and for the
+    /// mock backend.
+    pub avx2: bool,
+    /// Whether secret keys and shared secrets live in guard-paged, `mlock`ed
+    /// memory (the `secure-memory` feature on a unix target).
+    pub secure_memory: bool,
+}
+
+/// Describe the backend in use.
+pub fn backend_info() -> BackendInfo {
+    #[cfg(feature = "liboqs")]
+    {
+        BackendInfo {
+            backend: Backend::Liboqs,
+            liboqs_version: Some(crate::ffi::version()),
+            avx2: crate::ffi::avx2_active(),
+            secure_memory: crate::secret::SECURE,
+        }
+    }
+    #[cfg(not(feature = "liboqs"))]
+    {
+        BackendInfo {
+            backend: Backend::Mock,
+            liboqs_version: None,
+            avx2: false,
+            secure_memory: crate::secret::SECURE,
+        }
+    }
+}
@@ -0,0 +1,325 @@
+// Copyright (c) 2025 Orlando Trajano
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Composite ML-DSA-44 + traditional signatures (draft-ietf-lamps-pq-composite-sigs).
+//!
+//! Signing builds the message representative
+//!
+//! ```text
+//! M' = Prefix || Label || len(ctx) || ctx || PH(M)
+//! ```
+//!
+//! signs `M'` with ML-DSA-44 (FIPS 204 context string = `Label`) and with the
+//! traditional algorithm, and concatenates the two signatures. Verification
+//! succeeds only if **both** component signatures verify.
+//!
+//! # Encoding
+//!
+//! ML-DSA-44 components have fixed lengths, so concatenation is unambiguous:
+//!
+//! | value      | layout                     |
+//! |------------|----------------------------|
+//! | public key | `pk_MLDSA \|\| pk_Trad`    |
+//! | secret key | `sk_MLDSA \|\| sk_Trad`    |
+//! | signature  | `sig_MLDSA \|\| sig_Trad`  |
+//!
+//! Ed25519 keys/signatures are the raw 32/32/64-byte RFC 8032 encodings; the
+//! ECDSA-P256 public key is an uncompressed SEC1 point, the secret key the
+//! 32-byte scalar and the signature DER (variable length, hence last).
+//!
+//! As in the draft, `sk_MLDSA` is the 32-byte ML-DSA-44 key generation seed
+//! `ξ`. Signing re-expands it by replaying it as the randomness of
+//! [`Dilithium2::keypair`] under [`with_rng`], which costs one ML-DSA key
+//! generation per signature and fails with [`OqsError::Config`] while liboqs
+//! uses [`RandomSource::OpenSsl`](crate::rng::RandomSource::OpenSsl).
+
+use crate::rng::{with_rng, CurrentRng};
+use crate::sig::{Dilithium2, PublicKey, SecretKey, Signature, SignatureScheme};
+use crate::OqsError;
+use sha2::{Digest, Sha256, Sha512};
+use zeroize::Zeroizing;
+
+/// `Prefix` of the message representative (`"CompositeAlgorithmSignatures2025"`).
+pub const PREFIX: &[u8] = b"CompositeAlgorithmSignatures2025";
+
+/// Length of the ML-DSA-44 seed `ξ` stored in composite secret keys.
+const MLDSA_SEED_LEN: usize = 32;
+
+/// The traditional half of a composite and its draft-assigned label/pre-hash.
+trait Traditional {
+    const LABEL: &'static [u8];
+    const PUBLIC_KEY_LEN: usize;
+    const SECRET_KEY_LEN: usize;
+    const MAX_SIGNATURE_LEN: usize;
+
+    fn prehash(msg: &[u8]) -> Vec<u8>;
+    fn keypair() -> (Vec<u8>, Zeroizing<Vec<u8>>);
+    fn sign(sk: &[u8], m: &[u8]) -> Result<Vec<u8>, OqsError>;
+    fn verify(pk: &[u8], m: &[u8], sig: &[u8]) -> Result<(), OqsError>;
+}
+
+/// ML-DSA-44 + Ed25519, pre-hash SHA-512.
+pub struct MlDsa44Ed25519;
+
+impl Traditional for MlDsa44Ed25519 {
+    const LABEL: &'static [u8] = b"COMPSIG-MLDSA44-Ed25519-SHA512";
+    const PUBLIC_KEY_LEN: usize = 32;
+    const SECRET_KEY_LEN: usize = 32;
+    const MAX_SIGNATURE_LEN: usize = 64;
+
+    fn prehash(msg: &[u8]) -> Vec<u8> {
+        Sha512::digest(msg).to_vec()
+    }
+
+    fn keypair() -> (Vec<u8>, Zeroizing<Vec<u8>>) {
+        let mut seed = Zeroizing::new([0u8; 32]);
+        crate::rng::fill_bytes(&mut *seed);
+        let sk = ed25519_dalek::SigningKey::from_bytes(&seed);
+        (
+            sk.verifying_key().to_bytes().to_vec(),
+            Zeroizing::new(seed.to_vec()),
+        )
+    }
+
+    fn sign(sk: &[u8], m: &[u8]) -> Result<Vec<u8>, OqsError> {
+        use ed25519_dalek::Signer;
+
+        let seed: &[u8; 32] = sk.try_into().map_err(|_| OqsError::InvalidLength)?;
+        let sk = ed25519_dalek::SigningKey::from_bytes(seed);
+        Ok(sk.sign(m).to_bytes().to_vec())
+    }
+
+    fn verify(pk: &[u8], m: &[u8], sig: &[u8]) -> Result<(), OqsError> {
+        let pk: &[u8; 32] = pk.try_into().map_err(|_| OqsError::InvalidLength)?;
+        let pk = ed25519_dalek::VerifyingKey::from_bytes(pk)
+            .map_err(|_| OqsError::InvalidEncoding("Ed25519 public key"))?;
+        let sig = ed25519_dalek::Signature::from_slice(sig).map_err(|_| OqsError::InvalidLength)?;
+        pk.verify_strict(m, &sig).map_err(|_| OqsError::VerifyFail)
+    }
+}
+
+/// ML-DSA-44 + ECDSA-P256 (SHA-256), pre-hash SHA-256.
+pub struct MlDsa44EcdsaP256;
+
+impl Traditional for MlDsa44EcdsaP256 {
+    const LABEL: &'static [u8] = b"COMPSIG-MLDSA44-ECDSA-P256-SHA256";
+    const PUBLIC_KEY_LEN: usize = 65;
+    const SECRET_KEY_LEN: usize = 32;
+    const MAX_SIGNATURE_LEN: usize = 72;
+
+    fn prehash(msg: &[u8]) -> Vec<u8> {
+        Sha256::digest(msg).to_vec()
+    }
+
+    fn keypair() -> (Vec<u8>, Zeroizing<Vec<u8>>) {
+        let sk = p256::ecdsa::SigningKey::random(&mut CurrentRng);
+        let pk = sk.verifying_key().to_encoded_point(false);
+        (
+            pk.as_bytes().to_vec(),
+            Zeroizing::new(sk.to_bytes().to_vec()),
+        )
+    }
+
+    fn sign(sk: &[u8], m: &[u8]) -> Result<Vec<u8>, OqsError> {
+        use p256::ecdsa::signature::Signer;
+
+        let sk = p256::ecdsa::SigningKey::from_slice(sk)
+            .map_err(|_| OqsError::InvalidEncoding("P-256 scalar"))?;
+        let sig: p256::ecdsa::Signature = sk.sign(m);
+        Ok(sig.to_der().as_bytes().to_vec())
+    }
+
+    fn verify(pk: &[u8], m: &[u8], sig: &[u8]) -> Result<(), OqsError> {
+        use p256::ecdsa::signature::Verifier;
+
+        let pk = p256::ecdsa::VerifyingKey::from_sec1_bytes(pk)
+            .map_err(|_| OqsError::InvalidEncoding("P-256 point"))?;
+        let sig = p256::ecdsa::Signature::from_der(sig)
+            .map_err(|_| OqsError::InvalidEncoding("ECDSA DER signature"))?;
+        pk.verify(m, &sig).map_err(|_| OqsError::VerifyFail)
+    }
+}
+
+/// `Prefix || Label || len(ctx) || ctx || PH(M)`.
+fn message_representative<T: Traditional>(msg: &[u8], ctx: &[u8]) -> Result<Vec<u8>, OqsError> {
+    let ctx_len = u8::try_from(ctx.len()).map_err(|_| OqsError::InvalidLength)?;
+    let mut m = Vec::with_capacity(PREFIX.len() + T::LABEL.len() + 1 + ctx.len() + 64);
+    m.extend_from_slice(PREFIX);
+    m.extend_from_slice(T::LABEL);
+    m.push(ctx_len);
+    m.extend_from_slice(ctx);
+    m.extend_from_slice(&T::prehash(msg));
+    Ok(m)
+}
+
+/// Replays an ML-DSA seed as the randomness of [`Dilithium2::keypair`].
+///
+/// liboqs' ML-DSA key generation draws exactly the 32-byte seed. Anything past
+/// it (only the mock backend asks for more) is a SHA-512 expansion of the
+/// seed, so the same seed always yields the same key pair.
+struct SeedRng<'a> {
+    seed: &'a [u8; MLDSA_SEED_LEN],
+    pos: usize,
+    block: Zeroizing<[u8; 64]>,
+}
+
+impl rand_core::RngCore for SeedRng<'_> {
+    fn next_u32(&mut self) -> u32 {
+        rand_core::impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        rand_core::impls::next_u64_via_fill(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for byte in dest {
+            *byte = match self.pos.checked_sub(MLDSA_SEED_LEN) {
+                None => self.seed[self.pos],
+                Some(i) => {
+                    if i % 64 == 0 {
+                        let block = Sha512::new()
+                            .chain_update(self.seed)
+                            .chain_update(((i / 64) as u64).to_be_bytes())
+                            .finalize();
+                        self.block.copy_from_slice(&block);
+                    }
+                    self.block[i % 64]
+                }
+            };
+            self.pos += 1;
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl rand_core::CryptoRng for SeedRng<'_> {}
+
+/// ML-DSA-44 key pair for `seed`.
+fn expand_mldsa(seed: &[u8; MLDSA_SEED_LEN]) -> Result<(PublicKey, SecretKey), OqsError> {
+    let mut rng = SeedRng {
+        seed,
+        pos: 0,
+        block: Zeroizing::new([0; 64]),
+    };
+    with_rng(&mut rng, Dilithium2::keypair)
+}
+
+fn keypair<T: Traditional>() -> Result<(PublicKey, SecretKey), OqsError> {
+    let mut seed = Zeroizing::new([0u8; MLDSA_SEED_LEN]);
+    crate::rng::fill_bytes(&mut *seed);
+    let (pk_m, _) = expand_mldsa(&seed)?;
+    let (pk_t, sk_t) = T::keypair();
+    Ok((
+        PublicKey([pk_m.as_bytes(), &pk_t].concat()),
+        SecretKey([&seed[..], &sk_t].concat().into()),
+    ))
+}
+
+fn sign<T: Traditional>(sk: &SecretKey, msg: &[u8], ctx: &[u8]) -> Result<Signature, OqsError> {
+    if sk.len() != MLDSA_SEED_LEN + T::SECRET_KEY_LEN {
+        return Err(OqsError::InvalidLength);
+    }
+    let m = message_representative::<T>(msg, ctx)?;
+    let (seed, sk_t) = sk.as_bytes().split_at(MLDSA_SEED_LEN);
+    let seed: &[u8; MLDSA_SEED_LEN] = seed.try_into().map_err(|_| OqsError::InvalidLength)?;
+    let (_, sk_m) = expand_mldsa(seed)?;
+    let sig_m = Dilithium2::sign_with_context(&sk_m, &m, T::LABEL)?;
+    if sig_m.len() != Dilithium2::SIGNATURE_LEN {
+        return Err(OqsError::Internal("ML-DSA signature length"));
+    }
+    let sig_t = T::sign(sk_t, &m)?;
+    Ok(Signature([sig_m.as_bytes(), &sig_t].concat()))
+}
+
+fn verify<T: Traditional>(
+    pk: &PublicKey,
+    msg: &[u8],
+    sig: &Signature,
+    ctx: &[u8],
+) -> Result<(), OqsError> {
+    if pk.len() != Dilithium2::PUBLIC_KEY_LEN + T::PUBLIC_KEY_LEN
+        || sig.len() <= Dilithium2::SIGNATURE_LEN
+        || sig.len() > Dilithium2::SIGNATURE_LEN + T::MAX_SIGNATURE_LEN
+    {
+        return Err(OqsError::InvalidLength);
+    }
+    let m = message_representative::<T>(msg, ctx)?;
+    let (pk_m, pk_t) = pk.as_bytes().split_at(Dilithium2::PUBLIC_KEY_LEN);
+    let (sig_m, sig_t) = sig.as_bytes().split_at(Dilithium2::SIGNATURE_LEN);
+
+    // Check both components before deciding, so a bad half is not detectable
+    // from which one failed first.
+    let ok_m = Dilithium2::verify_with_context(
+        &PublicKey(pk_m.to_vec()),
+        &m,
+        &Signature(sig_m.to_vec()),
+        T::LABEL,
+    )
+    .is_ok();
+    let ok_t = T::verify(pk_t, &m, sig_t).is_ok();
+    if ok_m && ok_t {
+        Ok(())
+    } else {
+        Err(OqsError::VerifyFail)
+    }
+}
+
+macro_rules! composite_sig {
+    ($($name:ident => $display:literal),* $(,)?) => {$(
+        impl SignatureScheme for $name {
+            const NAME: &'static str = $display;
+            const PUBLIC_KEY_LEN: usize =
+                Dilithium2::PUBLIC_KEY_LEN + <$name as Traditional>::PUBLIC_KEY_LEN;
+            const SECRET_KEY_LEN: usize = MLDSA_SEED_LEN + <$name as Traditional>::SECRET_KEY_LEN;
+            const SIGNATURE_LEN: usize =
+                Dilithium2::SIGNATURE_LEN + <$name as Traditional>::MAX_SIGNATURE_LEN;
+            const STANDARDIZED: bool = false;
+
+            fn keypair() -> Result<(PublicKey, SecretKey), OqsError> {
+                keypair::<Self>()
+            }
+
+            fn sign(sk: &SecretKey, msg: &[u8]) -> Result<Signature, OqsError> {
+                sign::<Self>(sk, msg, &[])
+            }
+
+            fn verify(pk: &PublicKey, msg: &[u8], sig: &Signature) -> Result<(), OqsError> {
+                verify::<Self>(pk, msg, sig, &[])
+            }
+        }
+
+        impl $name {
+            /// Draft label, also used as the ML-DSA context string.
+            pub const LABEL: &'static [u8] = <$name as Traditional>::LABEL;
+
+            /// Signs with an application context string (at most 255 bytes).
+            pub fn sign_with_context(
+                sk: &SecretKey,
+                msg: &[u8],
+                ctx: &[u8],
+            ) -> Result<Signature, OqsError> {
+                sign::<Self>(sk, msg, ctx)
+            }
+
+            /// Verifies a signature produced by [`Self::sign_with_context`].
+            pub fn verify_with_context(
+                pk: &PublicKey,
+                msg: &[u8],
+                sig: &Signature,
+                ctx: &[u8],
+            ) -> Result<(), OqsError> {
+                verify::<Self>(pk, msg, sig, ctx)
+            }
+        }
+    )*};
+}
+
+composite_sig! {
+    MlDsa44Ed25519 => "MLDSA44-Ed25519-SHA512",
+    MlDsa44EcdsaP256 => "MLDSA44-ECDSA-P256-SHA256",
+}
@@ -4,7 +4,19 @@ use core::fmt;
 pub enum OqsError {
     NotImplemented,
     InvalidLength,
+    /// Input has the right length but does not decode (e.g. not a curve point).
+    InvalidEncoding(&'static str),
     VerifyFail,
+    /// A protocol state machine was misconfigured or driven out of order.
+    Protocol(&'static str),
+    /// A process-wide setting (e.g. the [`RandomSource`](crate::rng::RandomSource))
+    /// is already fixed or rules out the requested operation.
+    Config(&'static str),
+    /// A liboqs operation returned a non-success `OQS_STATUS`.
+    Backend {
+        op: &'static str,
+        status: i32,
+    },
     Internal(&'static str),
 }
 
@@ -13,7 +25,13 @@ impl fmt::Display for OqsError {
         match self {
             OqsError::NotImplemented => write!(f, "not implemented (enable `liboqs`)"),
             OqsError::InvalidLength => write!(f, "invalid length"),
+            OqsError::InvalidEncoding(m) => write!(f, "invalid encoding: {}", m),
             OqsError::VerifyFail => write!(f, "verification failed"),
+            OqsError::Protocol(m) => write!(f, "protocol error: {}", m),
+            OqsError::Config(m) => write!(f, "configuration error: {}", m),
+            OqsError::Backend { op, status } => {
+                write!(f, "liboqs {} failed (status {})", op, status)
+            }
             OqsError::Internal(m) => write!(f, "internal error: {}", m),
         }
     }
@@ -1,12 +1,16 @@
 // Minimal FFI surface for libOQS when `--features liboqs` is enabled.
 // Robust across libOQS versions: uses generic factories with ML-* and legacy fallbacks,
 // and matches the C layout of OQS_KEM and OQS_SIG so length fields are correct.
+// Secret outputs (secret keys, shared secrets) are written straight into a
+// `SecretBuf`, so they are cleansed on every exit path like `OQS_MEM_secure_free`.
 
 #![cfg(feature = "liboqs")]
 
+use crate::rng::RandomSource;
+use crate::secret::SecretBuf;
 use crate::OqsError;
 use core::ffi::{c_char, c_int, c_uint};
-use std::ffi::CString;
+use std::ffi::{CStr, CString};
 
 #[link(name = "oqs")]
 extern "C" {
@@ -17,6 +21,21 @@ extern "C" {
     fn OQS_KEM_encaps(kem: *const OQS_KEM, ct: *mut u8, ss: *mut u8, pub_key: *const u8) -> c_int;
     fn OQS_KEM_decaps(kem: *const OQS_KEM, ss: *mut u8, ct: *const u8, sec_key: *const u8)
         -> c_int;
+    #[cfg(any(feature = "xwing", feature = "testing"))]
+    fn OQS_KEM_keypair_derand(
+        kem: *const OQS_KEM,
+        pub_key: *mut u8,
+        sec_key: *mut u8,
+        seed: *const u8,
+    ) -> c_int;
+    #[cfg(any(feature = "xwing", feature = "testing"))]
+    fn OQS_KEM_encaps_derand(
+        kem: *const OQS_KEM,
+        ct: *mut u8,
+        ss: *mut u8,
+        pub_key: *const u8,
+        seed: *const u8,
+    ) -> c_int;
 
     // ---- Generic SIG factory + ops ----
     fn OQS_SIG_new(method_name: *const c_char) -> *mut OQS_SIG;
@@ -38,8 +57,41 @@ extern "C" {
         sig_len: usize,
         pub_key: *const u8,
     ) -> c_int;
+    #[cfg(any(feature = "composite", feature = "testing"))]
+    fn OQS_SIG_sign_with_ctx_str(
+        sig: *const OQS_SIG,
+        sig_out: *mut u8,
+        sig_len: *mut usize,
+        msg: *const u8,
+        msg_len: usize,
+        ctx: *const u8,
+        ctx_len: usize,
+        sec_key: *const u8,
+    ) -> c_int;
+    #[cfg(any(feature = "composite", feature = "testing"))]
+    fn OQS_SIG_verify_with_ctx_str(
+        sig: *const OQS_SIG,
+        msg: *const u8,
+        msg_len: usize,
+        sig_in: *const u8,
+        sig_len: usize,
+        ctx: *const u8,
+        ctx_len: usize,
+        pub_key: *const u8,
+    ) -> c_int;
+
+    // ---- Randomness ----
+    fn OQS_randombytes_custom_algorithm(algorithm_ptr: extern "C" fn(*mut u8, usize));
+    fn OQS_randombytes_switch_algorithm(algorithm: *const c_char) -> c_int;
+
+    // ---- Library / platform info ----
+    fn OQS_version() -> *const c_char;
+    fn OQS_CPU_has_extension(ext: c_int) -> c_int;
 }
 
+/// `OQS_CPU_EXT_AVX2` from `common.h` (enum position).
+const OQS_CPU_EXT_AVX2: c_int = 4;
+
 /// Full C layout for OQS_KEM (per liboqs headers)
 #[repr(C)]
 struct OQS_KEM {
@@ -51,7 +103,11 @@ struct OQS_KEM {
     length_secret_key: usize,
     length_ciphertext: usize,
     length_shared_secret: usize,
+    length_keypair_seed: usize,
+    length_encaps_seed: usize,
+    keypair_derand_fn: *const core::ffi::c_void,
     keypair_fn: *const core::ffi::c_void,
+    encaps_derand_fn: *const core::ffi::c_void,
     encaps_fn: *const core::ffi::c_void,
     decaps_fn: *const core::ffi::c_void,
 }
@@ -61,20 +117,97 @@ struct OQS_KEM {
 struct OQS_SIG {
     method_name: *const c_char,
     alg_version: *const c_char,
-    claimed_nist_level: c_uint,
-    euf_cma: c_uint,
+    claimed_nist_level: u8,
+    euf_cma: bool,
+    suf_cma: bool,
+    sig_with_ctx_support: bool,
     length_public_key: usize,
     length_secret_key: usize,
     length_signature: usize,
     keypair_fn: *const core::ffi::c_void,
     sign_fn: *const core::ffi::c_void,
+    sign_with_ctx_fn: *const core::ffi::c_void,
     verify_fn: *const core::ffi::c_void,
+    verify_with_ctx_fn: *const core::ffi::c_void,
+}
+
+// ---------- library / platform info ----------
+
+pub fn version() -> &'static str {
+    // SAFETY: OQS_version returns a pointer to a static NUL-terminated string.
+    let v = unsafe { CStr::from_ptr(OQS_version()) };
+    v.to_str().unwrap_or("unknown")
+}
+
+/// Whether liboqs runs its AVX2 code paths. Dist builds compile them in on
+/// x86_64 and pick them at runtime from `OQS_CPU_has_extension` (which is
+/// always 0 on other builds); native builds compile them in, and always use
+/// them, iff `OQS_USE_AVX2_INSTRUCTIONS` is set. The cfgs come from the
+/// linked `oqsconfig.h` (see `build.rs`).
+pub fn avx2_active() -> bool {
+    if cfg!(oqs_dist_build) {
+        // SAFETY: plain query, valid for any `OQS_CPU_EXT` value.
+        cfg!(oqs_dist_x86_64_build) && unsafe { OQS_CPU_has_extension(OQS_CPU_EXT_AVX2) == 1 }
+    } else {
+        cfg!(oqs_use_avx2_instructions)
+    }
+}
+
+// ---------- randomness ----------
+
+/// Points liboqs at `source`: [`RandomSource::System`] installs the
+/// [`crate::rng`] trampoline (OS randomness unless a caller RNG is scoped),
+/// [`RandomSource::OpenSsl`] selects liboqs' OpenSSL source.
+///
+/// liboqs keeps the choice in a plain global, so `crate::rng` calls this at
+/// most once, before the first liboqs operation that draws randomness.
+pub fn apply_random_source(source: RandomSource) -> Result<(), OqsError> {
+    match source {
+        // SAFETY: `rng_trampoline` matches the `void (*)(uint8_t *, size_t)`
+        // signature liboqs expects and is valid for the life of the process.
+        RandomSource::System => unsafe { OQS_randombytes_custom_algorithm(rng_trampoline) },
+        RandomSource::OpenSsl => {
+            let name = CString::new("OpenSSL").expect("CString::new failed");
+            let rc = unsafe { OQS_randombytes_switch_algorithm(name.as_ptr()) };
+            if rc != 0 {
+                return Err(OqsError::Backend {
+                    op: "randombytes switch",
+                    status: rc,
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Called before every liboqs operation that draws randomness.
+fn randomness_ready() -> Result<(), OqsError> {
+    if crate::rng::liboqs_source() == RandomSource::OpenSsl && crate::rng::is_scoped() {
+        return Err(OqsError::Config(
+            "caller-supplied RNG unavailable: liboqs uses OpenSSL randomness",
+        ));
+    }
+    Ok(())
+}
+
+extern "C" fn rng_trampoline(buf: *mut u8, len: usize) {
+    if buf.is_null() || len == 0 {
+        return;
+    }
+    // SAFETY: liboqs passes a writable buffer of `len` bytes.
+    let buf = unsafe { std::slice::from_raw_parts_mut(buf, len) };
+    // Unwinding across the C frames above us is undefined behaviour.
+    if std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| crate::rng::fill_bytes(buf)))
+        .is_err()
+    {
+        std::process::abort();
+    }
 }
 
 // ---------- helpers: factories with fallback names ----------
 
-unsafe fn kem_new_with_fallback() -> *mut OQS_KEM {
-    for name in ["ML-KEM-768", "Kyber768"] {
+unsafe fn kem_new_with_fallback(names: &[&str]) -> *mut OQS_KEM {
+    for &name in names {
         let cname = CString::new(name).expect("CString::new failed");
         let ptr = unsafe { OQS_KEM_new(cname.as_ptr()) };
         if !ptr.is_null() {
@@ -84,8 +217,8 @@ unsafe fn kem_new_with_fallback() -> *mut OQS_KEM {
     core::ptr::null_mut()
 }
 
-unsafe fn sig_new_with_fallback() -> *mut OQS_SIG {
-    for name in ["ML-DSA-44", "Dilithium2", "ML-DSA-2"] {
+unsafe fn sig_new_with_fallback(names: &[&str]) -> *mut OQS_SIG {
+    for &name in names {
         let cname = CString::new(name).expect("CString::new failed");
         let ptr = unsafe { OQS_SIG_new(cname.as_ptr()) };
         if !ptr.is_null() {
@@ -95,29 +228,34 @@ unsafe fn sig_new_with_fallback() -> *mut OQS_SIG {
     core::ptr::null_mut()
 }
 
-// ----------------- KEM (Kyber/ML-KEM-768) -----------------
+// ----------------- KEM (any liboqs method, by name) -----------------
 
-pub fn kyber768_keypair() -> Result<(Vec<u8>, Vec<u8>), OqsError> {
+pub fn kem_keypair(names: &[&str]) -> Result<(Vec<u8>, SecretBuf), OqsError> {
+    randomness_ready()?;
     unsafe {
-        let kem = kem_new_with_fallback();
+        let kem = kem_new_with_fallback(names);
         if kem.is_null() {
             return Err(OqsError::Internal("kem new"));
         }
         let lengths = &*kem;
         let mut pk = vec![0u8; lengths.length_public_key];
-        let mut sk = vec![0u8; lengths.length_secret_key];
+        let mut sk = SecretBuf::zeroed(lengths.length_secret_key);
         let rc = OQS_KEM_keypair(kem, pk.as_mut_ptr(), sk.as_mut_ptr());
         OQS_KEM_free(kem);
         if rc != 0 {
-            return Err(OqsError::Internal("kem keypair"));
+            return Err(OqsError::Backend {
+                op: "kem keypair",
+                status: rc,
+            });
         }
         Ok((pk, sk))
     }
 }
 
-pub fn kyber768_encapsulate(pk: &[u8]) -> Result<(Vec<u8>, Vec<u8>), OqsError> {
+pub fn kem_encapsulate(names: &[&str], pk: &[u8]) -> Result<(Vec<u8>, SecretBuf), OqsError> {
+    randomness_ready()?;
     unsafe {
-        let kem = kem_new_with_fallback();
+        let kem = kem_new_with_fallback(names);
         if kem.is_null() {
             return Err(OqsError::Internal("kem new"));
         }
@@ -129,19 +267,24 @@ pub fn kyber768_encapsulate(pk: &[u8]) -> Result<(Vec<u8>, Vec<u8>), OqsError> {
         }
 
         let mut ct = vec![0u8; lengths.length_ciphertext];
-        let mut ss = vec![0u8; lengths.length_shared_secret];
+        let mut ss = SecretBuf::zeroed(lengths.length_shared_secret);
         let rc = OQS_KEM_encaps(kem, ct.as_mut_ptr(), ss.as_mut_ptr(), pk.as_ptr());
         OQS_KEM_free(kem);
         if rc != 0 {
-            return Err(OqsError::Internal("kem encaps"));
+            return Err(OqsError::Backend {
+                op: "kem encaps",
+                status: rc,
+            });
         }
         Ok((ct, ss))
     }
 }
 
-pub fn kyber768_decapsulate(ct: &[u8], sk: &[u8]) -> Result<Vec<u8>, OqsError> {
+/// `OQS_KEM_decaps` reports success for implicitly rejected ciphertexts
+/// (the secret is then pseudorandom); a non-zero status is a real failure.
+pub fn kem_decapsulate(names: &[&str], ct: &[u8], sk: &[u8]) -> Result<SecretBuf, OqsError> {
     unsafe {
-        let kem = kem_new_with_fallback();
+        let kem = kem_new_with_fallback(names);
         if kem.is_null() {
             return Err(OqsError::Internal("kem new"));
         }
@@ -150,39 +293,111 @@ pub fn kyber768_decapsulate(ct: &[u8], sk: &[u8]) -> Result<Vec<u8>, OqsError> {
             OQS_KEM_free(kem);
             return Err(OqsError::InvalidLength);
         }
-        let mut ss = vec![0u8; lengths.length_shared_secret];
+        let mut ss = SecretBuf::zeroed(lengths.length_shared_secret);
         let rc = OQS_KEM_decaps(kem, ss.as_mut_ptr(), ct.as_ptr(), sk.as_ptr());
         OQS_KEM_free(kem);
         if rc != 0 {
-            return Err(OqsError::Internal("kem decaps"));
+            return Err(OqsError::Backend {
+                op: "kem decaps",
+                status: rc,
+            });
         }
         Ok(ss)
     }
 }
 
-// ----------------- SIG (Dilithium2 / ML-DSA-44/2) -----------------
+// ----------------- KEM derandomized entry points (liboqs >= 0.12) -----------------
+
+#[cfg(any(feature = "xwing", feature = "testing"))]
+pub fn kem_keypair_derand(names: &[&str], seed: &[u8]) -> Result<(Vec<u8>, SecretBuf), OqsError> {
+    unsafe {
+        let kem = kem_new_with_fallback(names);
+        if kem.is_null() {
+            return Err(OqsError::Internal("kem new"));
+        }
+        let lengths = &*kem;
+        if seed.len() != lengths.length_keypair_seed {
+            OQS_KEM_free(kem);
+            return Err(OqsError::InvalidLength);
+        }
+        let mut pk = vec![0u8; lengths.length_public_key];
+        let mut sk = SecretBuf::zeroed(lengths.length_secret_key);
+        let rc = OQS_KEM_keypair_derand(kem, pk.as_mut_ptr(), sk.as_mut_ptr(), seed.as_ptr());
+        OQS_KEM_free(kem);
+        if rc != 0 {
+            return Err(OqsError::Backend {
+                op: "kem keypair_derand",
+                status: rc,
+            });
+        }
+        Ok((pk, sk))
+    }
+}
 
-pub fn dilithium2_keypair() -> Result<(Vec<u8>, Vec<u8>), OqsError> {
+#[cfg(any(feature = "xwing", feature = "testing"))]
+pub fn kem_encapsulate_derand(
+    names: &[&str],
+    pk: &[u8],
+    seed: &[u8],
+) -> Result<(Vec<u8>, SecretBuf), OqsError> {
     unsafe {
-        let sig = sig_new_with_fallback();
+        let kem = kem_new_with_fallback(names);
+        if kem.is_null() {
+            return Err(OqsError::Internal("kem new"));
+        }
+        let lengths = &*kem;
+        if pk.len() != lengths.length_public_key || seed.len() != lengths.length_encaps_seed {
+            OQS_KEM_free(kem);
+            return Err(OqsError::InvalidLength);
+        }
+        let mut ct = vec![0u8; lengths.length_ciphertext];
+        let mut ss = SecretBuf::zeroed(lengths.length_shared_secret);
+        let rc = OQS_KEM_encaps_derand(
+            kem,
+            ct.as_mut_ptr(),
+            ss.as_mut_ptr(),
+            pk.as_ptr(),
+            seed.as_ptr(),
+        );
+        OQS_KEM_free(kem);
+        if rc != 0 {
+            return Err(OqsError::Backend {
+                op: "kem encaps_derand",
+                status: rc,
+            });
+        }
+        Ok((ct, ss))
+    }
+}
+
+// ----------------- SIG (any liboqs method, by name) -----------------
+
+pub fn sig_keypair(names: &[&str]) -> Result<(Vec<u8>, SecretBuf), OqsError> {
+    randomness_ready()?;
+    unsafe {
+        let sig = sig_new_with_fallback(names);
         if sig.is_null() {
             return Err(OqsError::Internal("sig new"));
         }
         let lengths = &*sig;
         let mut pk = vec![0u8; lengths.length_public_key];
-        let mut sk = vec![0u8; lengths.length_secret_key];
+        let mut sk = SecretBuf::zeroed(lengths.length_secret_key);
         let rc = OQS_SIG_keypair(sig, pk.as_mut_ptr(), sk.as_mut_ptr());
         OQS_SIG_free(sig);
         if rc != 0 {
-            return Err(OqsError::Internal("sig keypair"));
+            return Err(OqsError::Backend {
+                op: "sig keypair",
+                status: rc,
+            });
         }
         Ok((pk, sk))
     }
 }
 
-pub fn dilithium2_sign(sk: &[u8], msg: &[u8]) -> Result<Vec<u8>, OqsError> {
+pub fn sig_sign(names: &[&str], sk: &[u8], msg: &[u8]) -> Result<Vec<u8>, OqsError> {
+    randomness_ready()?;
     unsafe {
-        let sig = sig_new_with_fallback();
+        let sig = sig_new_with_fallback(names);
         if sig.is_null() {
             return Err(OqsError::Internal("sig new"));
         }
@@ -205,7 +420,10 @@ pub fn dilithium2_sign(sk: &[u8], msg: &[u8]) -> Result<Vec<u8>, OqsError> {
         );
         OQS_SIG_free(sig);
         if rc != 0 {
-            return Err(OqsError::Internal("sig sign"));
+            return Err(OqsError::Backend {
+                op: "sig sign",
+                status: rc,
+            });
         }
         if out_len > out.len() {
             return Err(OqsError::Internal("sig out_len"));
@@ -215,9 +433,9 @@ pub fn dilithium2_sign(sk: &[u8], msg: &[u8]) -> Result<Vec<u8>, OqsError> {
     }
 }
 
-pub fn dilithium2_verify(pk: &[u8], msg: &[u8], sig_in: &[u8]) -> Result<(), OqsError> {
+pub fn sig_verify(names: &[&str], pk: &[u8], msg: &[u8], sig_in: &[u8]) -> Result<(), OqsError> {
     unsafe {
-        let sig = sig_new_with_fallback();
+        let sig = sig_new_with_fallback(names);
         if sig.is_null() {
             return Err(OqsError::Internal("sig new"));
         }
@@ -243,3 +461,97 @@ pub fn dilithium2_verify(pk: &[u8], msg: &[u8], sig_in: &[u8]) -> Result<(), Oqs
         Ok(())
     }
 }
+
+/// ML-DSA style signing with a context string (FIPS 204, `ctx` <= 255 bytes).
+#[cfg(any(feature = "composite", feature = "testing"))]
+pub fn sig_sign_with_ctx(
+    names: &[&str],
+    sk: &[u8],
+    msg: &[u8],
+    ctx: &[u8],
+) -> Result<Vec<u8>, OqsError> {
+    randomness_ready()?;
+    unsafe {
+        let sig = sig_new_with_fallback(names);
+        if sig.is_null() {
+            return Err(OqsError::Internal("sig new"));
+        }
+        let lengths = &*sig;
+
+        if sk.len() != lengths.length_secret_key {
+            OQS_SIG_free(sig);
+            return Err(OqsError::InvalidLength);
+        }
+        if !ctx.is_empty() && !lengths.sig_with_ctx_support {
+            OQS_SIG_free(sig);
+            return Err(OqsError::NotImplemented);
+        }
+
+        let mut out = vec![0u8; lengths.length_signature];
+        let mut out_len: usize = 0;
+        let rc = OQS_SIG_sign_with_ctx_str(
+            sig,
+            out.as_mut_ptr(),
+            &mut out_len,
+            msg.as_ptr(),
+            msg.len(),
+            ctx.as_ptr(),
+            ctx.len(),
+            sk.as_ptr(),
+        );
+        OQS_SIG_free(sig);
+        if rc != 0 {
+            return Err(OqsError::Backend {
+                op: "sig sign",
+                status: rc,
+            });
+        }
+        if out_len > out.len() {
+            return Err(OqsError::Internal("sig out_len"));
+        }
+        out.truncate(out_len);
+        Ok(out)
+    }
+}
+
+#[cfg(any(feature = "composite", feature = "testing"))]
+pub fn sig_verify_with_ctx(
+    names: &[&str],
+    pk: &[u8],
+    msg: &[u8],
+    sig_in: &[u8],
+    ctx: &[u8],
+) -> Result<(), OqsError> {
+    unsafe {
+        let sig = sig_new_with_fallback(names);
+        if sig.is_null() {
+            return Err(OqsError::Internal("sig new"));
+        }
+        let lengths = &*sig;
+
+        if pk.len() != lengths.length_public_key || sig_in.len() > lengths.length_signature {
+            OQS_SIG_free(sig);
+            return Err(OqsError::InvalidLength);
+        }
+        if !ctx.is_empty() && !lengths.sig_with_ctx_support {
+            OQS_SIG_free(sig);
+            return Err(OqsError::NotImplemented);
+        }
+
+        let rc = OQS_SIG_verify_with_ctx_str(
+            sig,
+            msg.as_ptr(),
+            msg.len(),
+            sig_in.as_ptr(),
+            sig_in.len(),
+            ctx.as_ptr(),
+            ctx.len(),
+            pk.as_ptr(),
+        );
+        OQS_SIG_free(sig);
+        if rc != 0 {
+            return Err(OqsError::VerifyFail);
+        }
+        Ok(())
+    }
+}
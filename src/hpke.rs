@@ -0,0 +1,347 @@
+// Copyright (c) 2025 Orlando Trajano
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Hybrid Public Key Encryption (RFC 9180) over post-quantum [`Kem`]s.
+//!
+//! Supports the `Base` and `Psk` modes (the `Auth*` modes need an
+//! authenticated KEM, which ML-KEM and X-Wing are not) with HKDF-SHA256 and
+//! AES-GCM / ChaCha20-Poly1305. As in the PQ HPKE drafts, the KEM shared
+//! secret is used directly as the HPKE `shared_secret`, and `enc` is the KEM
+//! ciphertext.
+//!
+//! | KEM                     | `kem_id` |
+//! |-------------------------|----------|
+//! | ML-KEM-768 (`Kyber768`) | `0x0041` |
+//! | X-Wing (`XWing`)        | `0x647a` |
+
+use crate::kem::{Ciphertext, Kem, Kyber768, PublicKey, SecretKey, SharedSecret};
+use crate::OqsError;
+use aes_gcm::aead::{Aead as _, KeyInit, Payload};
+use core::marker::PhantomData;
+use hkdf::Hkdf;
+use sha2::Sha256;
+use zeroize::Zeroizing;
+
+/// `kdf_id` of HKDF-SHA256, the only KDF offered here.
+pub const KDF_ID_HKDF_SHA256: u16 = 0x0001;
+
+const NH: usize = 32;
+const NN: usize = 12;
+
+const MODE_BASE: u8 = 0x00;
+const MODE_PSK: u8 = 0x01;
+
+/// RFC 9180 §5.1.2: a PSK must carry at least 32 bytes of entropy.
+const MIN_PSK_LEN: usize = 32;
+
+/// A [`Kem`] with an HPKE `kem_id` codepoint.
+pub trait HpkeKem: Kem {
+    const KEM_ID: u16;
+}
+
+impl HpkeKem for Kyber768 {
+    const KEM_ID: u16 = 0x0041;
+}
+
+#[cfg(feature = "xwing")]
+impl HpkeKem for crate::xwing::XWing {
+    const KEM_ID: u16 = 0x647a;
+}
+
+/// An HPKE AEAD with its `aead_id` codepoint.
+pub trait Aead {
+    const AEAD_ID: u16;
+    /// Key length in bytes (`Nk`).
+    const KEY_LEN: usize;
+
+    fn seal(key: &[u8], nonce: &[u8; NN], aad: &[u8], pt: &[u8]) -> Result<Vec<u8>, OqsError>;
+    fn open(key: &[u8], nonce: &[u8; NN], aad: &[u8], ct: &[u8]) -> Result<Vec<u8>, OqsError>;
+}
+
+macro_rules! hpke_aead {
+    ($(
+        $(#[$attr:meta])*
+        $name:ident($cipher:ty) { id: $id:expr, key_len: $nk:expr $(,)? }
+    )*) => {$(
+        $(#[$attr])*
+        pub struct $name;
+
+        impl Aead for $name {
+            const AEAD_ID: u16 = $id;
+            const KEY_LEN: usize = $nk;
+
+            fn seal(key: &[u8], nonce: &[u8; NN], aad: &[u8], pt: &[u8]) -> Result<Vec<u8>, OqsError> {
+                let cipher = <$cipher>::new_from_slice(key).map_err(|_| OqsError::InvalidLength)?;
+                cipher
+                    .encrypt(nonce.into(), Payload { msg: pt, aad })
+                    .map_err(|_| OqsError::Internal("aead seal"))
+            }
+
+            fn open(key: &[u8], nonce: &[u8; NN], aad: &[u8], ct: &[u8]) -> Result<Vec<u8>, OqsError> {
+                let cipher = <$cipher>::new_from_slice(key).map_err(|_| OqsError::InvalidLength)?;
+                cipher
+                    .decrypt(nonce.into(), Payload { msg: ct, aad })
+                    .map_err(|_| OqsError::VerifyFail)
+            }
+        }
+    )*};
+}
+
+hpke_aead! {
+    /// AES-128-GCM (`aead_id = 0x0001`)
+    Aes128Gcm(aes_gcm::Aes128Gcm) { id: 0x0001, key_len: 16 }
+    /// AES-256-GCM (`aead_id = 0x0002`)
+    Aes256Gcm(aes_gcm::Aes256Gcm) { id: 0x0002, key_len: 32 }
+    /// ChaCha20-Poly1305 (`aead_id = 0x0003`)
+    ChaCha20Poly1305(chacha20poly1305::ChaCha20Poly1305) { id: 0x0003, key_len: 32 }
+}
+
+/// `"HPKE" || I2OSP(kem_id, 2) || I2OSP(kdf_id, 2) || I2OSP(aead_id, 2)`
+fn suite_id<K: HpkeKem, A: Aead>() -> [u8; 10] {
+    let mut id = *b"HPKE\0\0\0\0\0\0";
+    id[4..6].copy_from_slice(&K::KEM_ID.to_be_bytes());
+    id[6..8].copy_from_slice(&KDF_ID_HKDF_SHA256.to_be_bytes());
+    id[8..10].copy_from_slice(&A::AEAD_ID.to_be_bytes());
+    id
+}
+
+fn labeled_extract(suite: &[u8], salt: &[u8], label: &[u8], ikm: &[u8]) -> Zeroizing<Vec<u8>> {
+    let labeled_ikm = Zeroizing::new([b"HPKE-v1", suite, label, ikm].concat());
+    let (prk, _) = Hkdf::<Sha256>::extract(Some(salt), &labeled_ikm);
+    Zeroizing::new(prk.to_vec())
+}
+
+fn labeled_expand(
+    suite: &[u8],
+    prk: &[u8],
+    label: &[u8],
+    info: &[u8],
+    len: usize,
+) -> Result<Zeroizing<Vec<u8>>, OqsError> {
+    let len_be = u16::try_from(len)
+        .map_err(|_| OqsError::InvalidLength)?
+        .to_be_bytes();
+    let labeled_info = [&len_be[..], b"HPKE-v1", suite, label, info].concat();
+    let hk = Hkdf::<Sha256>::from_prk(prk).map_err(|_| OqsError::Internal("hkdf prk"))?;
+    let mut out = Zeroizing::new(vec![0u8; len]);
+    hk.expand(&labeled_info, &mut out)
+        .map_err(|_| OqsError::InvalidLength)?;
+    Ok(out)
+}
+
+/// Keys and sequence state shared by both context directions.
+struct Schedule {
+    suite: [u8; 10],
+    key: Zeroizing<Vec<u8>>,
+    base_nonce: [u8; NN],
+    seq: u64,
+    exporter_secret: Zeroizing<Vec<u8>>,
+}
+
+impl Schedule {
+    fn new<K: HpkeKem, A: Aead>(
+        mode: u8,
+        shared_secret: &SharedSecret,
+        info: &[u8],
+        psk: &[u8],
+        psk_id: &[u8],
+    ) -> Result<Self, OqsError> {
+        // RFC 9180 §5.1: a PSK and its id are given together, and only in PSK mode.
+        let has_psk = !psk.is_empty();
+        if has_psk == psk_id.is_empty() {
+            return Err(OqsError::Protocol(
+                "hpke: psk and psk_id must be given together",
+            ));
+        }
+        if has_psk != (mode == MODE_PSK) {
+            return Err(OqsError::Protocol("hpke: psk does not match mode"));
+        }
+        if has_psk && psk.len() < MIN_PSK_LEN {
+            return Err(OqsError::Protocol("hpke: psk shorter than 32 bytes"));
+        }
+        let suite = suite_id::<K, A>();
+        let psk_id_hash = labeled_extract(&suite, b"", b"psk_id_hash", psk_id);
+        let info_hash = labeled_extract(&suite, b"", b"info_hash", info);
+        let context = [&[mode][..], &psk_id_hash, &info_hash].concat();
+
+        let secret = labeled_extract(&suite, shared_secret.as_bytes(), b"secret", psk);
+        let key = labeled_expand(&suite, &secret, b"key", &context, A::KEY_LEN)?;
+        let nonce = labeled_expand(&suite, &secret, b"base_nonce", &context, NN)?;
+        let exporter_secret = labeled_expand(&suite, &secret, b"exp", &context, NH)?;
+
+        let mut base_nonce = [0u8; NN];
+        base_nonce.copy_from_slice(&nonce);
+        Ok(Self {
+            suite,
+            key,
+            base_nonce,
+            seq: 0,
+            exporter_secret,
+        })
+    }
+
+    /// `base_nonce XOR I2OSP(seq, Nn)`
+    fn nonce(&self) -> Result<[u8; NN], OqsError> {
+        if self.seq == u64::MAX {
+            return Err(OqsError::Internal("hpke message limit reached"));
+        }
+        let mut nonce = self.base_nonce;
+        for (n, s) in nonce[NN - 8..].iter_mut().zip(self.seq.to_be_bytes()) {
+            *n ^= s;
+        }
+        Ok(nonce)
+    }
+
+    fn export(&self, exporter_context: &[u8], len: usize) -> Result<Vec<u8>, OqsError> {
+        if len > 255 * NH {
+            return Err(OqsError::InvalidLength);
+        }
+        let out = labeled_expand(
+            &self.suite,
+            &self.exporter_secret,
+            b"sec",
+            exporter_context,
+            len,
+        )?;
+        Ok(out.to_vec())
+    }
+}
+
+/// Sender side of an HPKE context (encrypts).
+pub struct SenderContext<A: Aead> {
+    schedule: Schedule,
+    _aead: PhantomData<A>,
+}
+
+/// Recipient side of an HPKE context (decrypts).
+pub struct RecipientContext<A: Aead> {
+    schedule: Schedule,
+    _aead: PhantomData<A>,
+}
+
+impl<A: Aead> SenderContext<A> {
+    /// Encrypts the next message in sequence.
+    pub fn seal(&mut self, aad: &[u8], pt: &[u8]) -> Result<Vec<u8>, OqsError> {
+        let ct = A::seal(&self.schedule.key, &self.schedule.nonce()?, aad, pt)?;
+        self.schedule.seq += 1;
+        Ok(ct)
+    }
+
+    /// Secret export (RFC 9180 §5.3).
+    pub fn export(&self, exporter_context: &[u8], len: usize) -> Result<Vec<u8>, OqsError> {
+        self.schedule.export(exporter_context, len)
+    }
+}
+
+impl<A: Aead> RecipientContext<A> {
+    /// Decrypts the next message in sequence; `VerifyFail` if authentication fails.
+    pub fn open(&mut self, aad: &[u8], ct: &[u8]) -> Result<Vec<u8>, OqsError> {
+        // A failed open does not consume the sequence number.
+        let pt = A::open(&self.schedule.key, &self.schedule.nonce()?, aad, ct)?;
+        self.schedule.seq += 1;
+        Ok(pt)
+    }
+
+    /// Secret export (RFC 9180 §5.3).
+    pub fn export(&self, exporter_context: &[u8], len: usize) -> Result<Vec<u8>, OqsError> {
+        self.schedule.export(exporter_context, len)
+    }
+}
+
+fn setup_s<K: HpkeKem, A: Aead>(
+    mode: u8,
+    pk_r: &PublicKey,
+    info: &[u8],
+    psk: &[u8],
+    psk_id: &[u8],
+) -> Result<(Vec<u8>, SenderContext<A>), OqsError> {
+    let (enc, ss) = K::encapsulate(pk_r)?;
+    let schedule = Schedule::new::<K, A>(mode, &ss, info, psk, psk_id)?;
+    Ok((
+        enc.0,
+        SenderContext {
+            schedule,
+            _aead: PhantomData,
+        },
+    ))
+}
+
+fn setup_r<K: HpkeKem, A: Aead>(
+    mode: u8,
+    enc: &[u8],
+    sk_r: &SecretKey,
+    info: &[u8],
+    psk: &[u8],
+    psk_id: &[u8],
+) -> Result<RecipientContext<A>, OqsError> {
+    if enc.len() != K::CIPHERTEXT_LEN {
+        return Err(OqsError::InvalidLength);
+    }
+    let ss = K::decapsulate(&Ciphertext(enc.to_vec()), sk_r)?;
+    let schedule = Schedule::new::<K, A>(mode, &ss, info, psk, psk_id)?;
+    Ok(RecipientContext {
+        schedule,
+        _aead: PhantomData,
+    })
+}
+
+/// `SetupBaseS`: returns `enc` (the KEM ciphertext) and the sender context.
+pub fn setup_base_s<K: HpkeKem, A: Aead>(
+    pk_r: &PublicKey,
+    info: &[u8],
+) -> Result<(Vec<u8>, SenderContext<A>), OqsError> {
+    setup_s::<K, A>(MODE_BASE, pk_r, info, b"", b"")
+}
+
+/// `SetupBaseR`
+pub fn setup_base_r<K: HpkeKem, A: Aead>(
+    enc: &[u8],
+    sk_r: &SecretKey,
+    info: &[u8],
+) -> Result<RecipientContext<A>, OqsError> {
+    setup_r::<K, A>(MODE_BASE, enc, sk_r, info, b"", b"")
+}
+
+/// `SetupPSKS`; `psk` (at least 32 bytes) and `psk_id` must both be non-empty.
+pub fn setup_psk_s<K: HpkeKem, A: Aead>(
+    pk_r: &PublicKey,
+    info: &[u8],
+    psk: &[u8],
+    psk_id: &[u8],
+) -> Result<(Vec<u8>, SenderContext<A>), OqsError> {
+    setup_s::<K, A>(MODE_PSK, pk_r, info, psk, psk_id)
+}
+
+/// `SetupPSKR`; `psk` (at least 32 bytes) and `psk_id` must both be non-empty.
+pub fn setup_psk_r<K: HpkeKem, A: Aead>(
+    enc: &[u8],
+    sk_r: &SecretKey,
+    info: &[u8],
+    psk: &[u8],
+    psk_id: &[u8],
+) -> Result<RecipientContext<A>, OqsError> {
+    setup_r::<K, A>(MODE_PSK, enc, sk_r, info, psk, psk_id)
+}
+
+/// Single-shot Base-mode encryption; returns `(enc, ciphertext)`.
+pub fn seal_base<K: HpkeKem, A: Aead>(
+    pk_r: &PublicKey,
+    info: &[u8],
+    aad: &[u8],
+    pt: &[u8],
+) -> Result<(Vec<u8>, Vec<u8>), OqsError> {
+    let (enc, mut ctx) = setup_base_s::<K, A>(pk_r, info)?;
+    let ct = ctx.seal(aad, pt)?;
+    Ok((enc, ct))
+}
+
+/// Single-shot Base-mode decryption.
+pub fn open_base<K: HpkeKem, A: Aead>(
+    enc: &[u8],
+    sk_r: &SecretKey,
+    info: &[u8],
+    aad: &[u8],
+    ct: &[u8],
+) -> Result<Vec<u8>, OqsError> {
+    setup_base_r::<K, A>(enc, sk_r, info)?.open(aad, ct)
+}
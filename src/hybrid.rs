@@ -0,0 +1,241 @@
+// Copyright (c) 2025 Orlando Trajano
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Generic hybrid KEM over any two [`Kem`] implementations.
+//!
+//! # Encoding
+//!
+//! All components have fixed lengths, so plain concatenation is unambiguous:
+//!
+//! | value      | layout                   | length                                                  |
+//! |------------|--------------------------|---------------------------------------------------------|
+//! | public key | `pk_A \|\| pk_B`         | `A::PUBLIC_KEY_LEN + B::PUBLIC_KEY_LEN`                 |
+//! | secret key | `sk_A \|\| sk_B \|\| pk` | `A::SECRET_KEY_LEN + B::SECRET_KEY_LEN + PUBLIC_KEY_LEN` |
+//! | ciphertext | `ct_A \|\| ct_B`         | `A::CIPHERTEXT_LEN + B::CIPHERTEXT_LEN`                 |
+//!
+//! The shared secret is `C::combine` over both component secrets, the full
+//! hybrid ciphertext and the full hybrid public key, so a combiner always sees
+//! the whole transcript. The secret key carries the hybrid public key because
+//! not every component secret key embeds its own.
+
+use crate::kem::{Ciphertext, Kem, PublicKey, SecretKey, SharedSecret};
+use crate::OqsError;
+use core::marker::PhantomData;
+use sha3::{Digest, Sha3_256};
+use zeroize::Zeroizing;
+
+/// Everything a [`Combiner`] must bind.
+pub struct Transcript<'a> {
+    /// Shared secret of the first component (`A`).
+    pub ss_a: &'a [u8],
+    /// Shared secret of the second component (`B`).
+    pub ss_b: &'a [u8],
+    /// Hybrid ciphertext, `ct_A || ct_B`.
+    pub ciphertext: &'a [u8],
+    /// Hybrid public key, `pk_A || pk_B`.
+    pub public_key: &'a [u8],
+}
+
+/// KDF deriving the hybrid shared secret from a [`Transcript`].
+pub trait Combiner {
+    /// Combiner part of `HybridKem::NAME` (`"<A>+<B>/<combiner>"`).
+    const NAME: &'static str;
+    /// Output length in bytes.
+    const SHARED_SECRET_LEN: usize;
+
+    /// Must return exactly `SHARED_SECRET_LEN` bytes.
+    fn combine(transcript: &Transcript<'_>) -> Zeroizing<Vec<u8>>;
+}
+
+/// `SHA3-256(ss_A || ss_B || ct || pk || label)` with a fixed domain label.
+pub struct Sha3_256Combiner;
+
+impl Sha3_256Combiner {
+    /// Domain-separation label appended to the hash input.
+    pub const LABEL: &'static [u8] = b"oqs-safe hybrid kem v1";
+}
+
+impl Combiner for Sha3_256Combiner {
+    const NAME: &'static str = "hybrid-sha3-256";
+    const SHARED_SECRET_LEN: usize = 32;
+
+    fn combine(t: &Transcript<'_>) -> Zeroizing<Vec<u8>> {
+        let mut h = Sha3_256::new();
+        h.update(t.ss_a);
+        h.update(t.ss_b);
+        h.update(t.ciphertext);
+        h.update(t.public_key);
+        h.update(Self::LABEL);
+        Zeroizing::new(h.finalize().to_vec())
+    }
+}
+
+/// `A` + `B` hybrid KEM whose shared secret is derived by `C`.
+///
+/// Breaking it requires breaking both `A` and `B` (given a sound combiner),
+/// e.g. `HybridKem<Kyber768, EcdhP256>` for "PQ + classical" policies.
+///
+/// `NAME` is `"<A::NAME>+<B::NAME>/<C::NAME>"`, e.g.
+/// `"ML-KEM-768+ECDH-P256/hybrid-sha3-256"`, so every instantiation reports
+/// its own algorithm (envelopes, [`DynKem::name`](crate::kem::DynKem::name),
+/// Noise protocol names).
+pub struct HybridKem<A, B, C = Sha3_256Combiner>(PhantomData<(A, B, C)>);
+
+impl<A: Kem, B: Kem, C: Combiner> HybridKem<A, B, C> {
+    const NAME_BUF: NameBuf = NameBuf::concat(&[A::NAME, "+", B::NAME, "/", C::NAME]);
+}
+
+impl<A: Kem, B: Kem, C: Combiner> Kem for HybridKem<A, B, C> {
+    const NAME: &'static str = Self::NAME_BUF.as_str();
+    const PUBLIC_KEY_LEN: usize = A::PUBLIC_KEY_LEN + B::PUBLIC_KEY_LEN;
+    const SECRET_KEY_LEN: usize = A::SECRET_KEY_LEN + B::SECRET_KEY_LEN + Self::PUBLIC_KEY_LEN;
+    const CIPHERTEXT_LEN: usize = A::CIPHERTEXT_LEN + B::CIPHERTEXT_LEN;
+    const SHARED_SECRET_LEN: usize = C::SHARED_SECRET_LEN;
+
+    fn keypair() -> Result<(PublicKey, SecretKey), OqsError> {
+        let (pk_a, sk_a) = A::keypair()?;
+        let (pk_b, sk_b) = B::keypair()?;
+        let pk = [pk_a.as_bytes(), pk_b.as_bytes()].concat();
+        let sk = [sk_a.as_bytes(), sk_b.as_bytes(), &pk].concat();
+        Ok((PublicKey(pk.into()), SecretKey(sk.into())))
+    }
+
+    fn encapsulate(pk: &PublicKey) -> Result<(Ciphertext, SharedSecret), OqsError> {
+        if pk.len() != Self::PUBLIC_KEY_LEN {
+            return Err(OqsError::InvalidLength);
+        }
+        let (pk_a, pk_b) = pk.as_bytes().split_at(A::PUBLIC_KEY_LEN);
+        let (ct_a, ss_a) = A::encapsulate(&PublicKey(pk_a.into()))?;
+        let (ct_b, ss_b) = B::encapsulate(&PublicKey(pk_b.into()))?;
+        let ct = [ct_a.as_bytes(), ct_b.as_bytes()].concat();
+        let ss = combine::<C>(&ss_a, &ss_b, &ct, pk.as_bytes())?;
+        Ok((Ciphertext(ct), ss))
+    }
+
+    fn decapsulate(ct: &Ciphertext, sk: &SecretKey) -> Result<SharedSecret, OqsError> {
+        if ct.len() != Self::CIPHERTEXT_LEN || sk.len() != Self::SECRET_KEY_LEN {
+            return Err(OqsError::InvalidLength);
+        }
+        let (ct_a, ct_b) = ct.as_bytes().split_at(A::CIPHERTEXT_LEN);
+        let (sk_a, rest) = sk.as_bytes().split_at(A::SECRET_KEY_LEN);
+        let (sk_b, pk) = rest.split_at(B::SECRET_KEY_LEN);
+        let ss_a = A::decapsulate(&Ciphertext(ct_a.to_vec()), &SecretKey(sk_a.into()))?;
+        let ss_b = B::decapsulate(&Ciphertext(ct_b.to_vec()), &SecretKey(sk_b.into()))?;
+        combine::<C>(&ss_a, &ss_b, ct.as_bytes(), pk)
+    }
+}
+
+/// Fixed-capacity buffer for building `HybridKem::NAME` in a const context.
+/// Names longer than 255 bytes (the envelope limit) fail to compile.
+struct NameBuf {
+    bytes: [u8; 255],
+    len: usize,
+}
+
+impl NameBuf {
+    const fn concat(parts: &[&str]) -> Self {
+        let mut bytes = [0u8; 255];
+        let mut len = 0;
+        let mut i = 0;
+        while i < parts.len() {
+            let part = parts[i].as_bytes();
+            let mut j = 0;
+            while j < part.len() {
+                bytes[len] = part[j];
+                len += 1;
+                j += 1;
+            }
+            i += 1;
+        }
+        Self { bytes, len }
+    }
+
+    const fn as_str(&'static self) -> &'static str {
+        // SAFETY: `len <= bytes.len()`, as `concat` indexes every byte it
+        // writes. (`<[u8]>::split_at` is not const on the MSRV.)
+        let bytes = unsafe { core::slice::from_raw_parts(self.bytes.as_ptr(), self.len) };
+        match core::str::from_utf8(bytes) {
+            Ok(s) => s,
+            // Unreachable: concatenated `str`s are UTF-8.
+            Err(_) => panic!("hybrid KEM name is not UTF-8"),
+        }
+    }
+}
+
+fn combine<C: Combiner>(
+    ss_a: &SharedSecret,
+    ss_b: &SharedSecret,
+    ct: &[u8],
+    pk: &[u8],
+) -> Result<SharedSecret, OqsError> {
+    let out = C::combine(&Transcript {
+        ss_a: ss_a.as_bytes(),
+        ss_b: ss_b.as_bytes(),
+        ciphertext: ct,
+        public_key: pk,
+    });
+    if out.len() != C::SHARED_SECRET_LEN {
+        return Err(OqsError::Internal("combiner output length"));
+    }
+    Ok(SharedSecret(out.as_slice().into()))
+}
+
+/// ECDH over NIST P-256 wrapped as an ephemeral-static [`Kem`], for use as
+/// the classical half of a [`HybridKem`].
+///
+/// Public key and ciphertext are uncompressed SEC1 points (65 bytes), the
+/// secret key is the 32-byte scalar and the shared secret is the 32-byte
+/// x-coordinate of the ECDH result.
+#[cfg(feature = "ecdh_p256")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ecdh_p256")))]
+pub struct EcdhP256;
+
+#[cfg(feature = "ecdh_p256")]
+impl Kem for EcdhP256 {
+    const NAME: &'static str = "ECDH-P256";
+    const PUBLIC_KEY_LEN: usize = 65;
+    const SECRET_KEY_LEN: usize = 32;
+    const CIPHERTEXT_LEN: usize = 65;
+    const SHARED_SECRET_LEN: usize = 32;
+
+    fn keypair() -> Result<(PublicKey, SecretKey), OqsError> {
+        use p256::elliptic_curve::sec1::ToEncodedPoint;
+
+        let sk = p256::SecretKey::random(&mut crate::rng::CurrentRng);
+        let pk = sk.public_key().to_encoded_point(false);
+        let scalar = Zeroizing::new(sk.to_bytes());
+        Ok((
+            PublicKey(pk.as_bytes().into()),
+            SecretKey(scalar[..].into()),
+        ))
+    }
+
+    fn encapsulate(pk: &PublicKey) -> Result<(Ciphertext, SharedSecret), OqsError> {
+        use p256::elliptic_curve::sec1::ToEncodedPoint;
+
+        if pk.len() != Self::PUBLIC_KEY_LEN {
+            return Err(OqsError::InvalidLength);
+        }
+        let peer = p256::PublicKey::from_sec1_bytes(pk.as_bytes())
+            .map_err(|_| OqsError::InvalidEncoding("P-256 point"))?;
+        let eph = p256::ecdh::EphemeralSecret::random(&mut crate::rng::CurrentRng);
+        let ss = eph.diffie_hellman(&peer);
+        let ct = eph.public_key().to_encoded_point(false);
+        Ok((
+            Ciphertext(ct.as_bytes().to_vec()),
+            SharedSecret(ss.raw_secret_bytes()[..].into()),
+        ))
+    }
+
+    fn decapsulate(ct: &Ciphertext, sk: &SecretKey) -> Result<SharedSecret, OqsError> {
+        if ct.len() != Self::CIPHERTEXT_LEN || sk.len() != Self::SECRET_KEY_LEN {
+            return Err(OqsError::InvalidLength);
+        }
+        let peer = p256::PublicKey::from_sec1_bytes(ct.as_bytes())
+            .map_err(|_| OqsError::InvalidEncoding("P-256 point"))?;
+        let sk = p256::SecretKey::from_slice(sk.as_bytes())
+            .map_err(|_| OqsError::InvalidEncoding("P-256 scalar"))?;
+        let ss = p256::ecdh::diffie_hellman(sk.to_nonzero_scalar(), peer.as_affine());
+        Ok(SharedSecret(ss.raw_secret_bytes()[..].into()))
+    }
+}
@@ -3,41 +3,48 @@
 
 //! KEM API with safe accessors and feature-gated RNG for the mock backend.
 
+use crate::secret::SecretBuf;
 use crate::OqsError;
+use std::sync::Arc;
+use subtle::{Choice, ConstantTimeEq};
 use zeroize::Zeroize;
 
-#[cfg(not(feature = "liboqs"))]
-use rand_core::{OsRng, RngCore};
+use rand_core::CryptoRngCore;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-/// Public key newtype
-#[derive(Clone, Debug)]
+/// Public key newtype (reference-counted: clones share one buffer)
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct PublicKey(pub(crate) Vec<u8>);
+pub struct PublicKey(pub(crate) Arc<[u8]>);
 
 /// Secret key newtype (zeroizes on drop)
 #[derive(Clone, Debug, Zeroize)]
 #[zeroize(drop)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct SecretKey(pub(crate) Vec<u8>);
+pub struct SecretKey(pub(crate) SecretBuf);
 
 /// Ciphertext newtype
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Ciphertext(pub(crate) Vec<u8>);
 
-/// Shared secret newtype
+/// Shared secret newtype (zeroizes on drop)
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct SharedSecret(pub(crate) Vec<u8>);
+pub struct SharedSecret(pub(crate) SecretBuf);
 
 impl PublicKey {
     #[inline]
     pub fn as_bytes(&self) -> &[u8] {
         &self.0
     }
+    /// Shared handle to the key bytes; no copy is made.
+    #[inline]
+    pub fn to_shared(&self) -> Arc<[u8]> {
+        Arc::clone(&self.0)
+    }
     #[inline]
     pub fn len(&self) -> usize {
         self.0.len()
@@ -90,67 +97,721 @@ impl SharedSecret {
     }
 }
 
+// Secrets compare in constant time (only the lengths, which are public,
+// may short-circuit); `==` on them is safe to use directly.
+impl ConstantTimeEq for SecretKey {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.as_bytes().ct_eq(other.as_bytes())
+    }
+}
+impl PartialEq for SecretKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+impl Eq for SecretKey {}
+impl ConstantTimeEq for SharedSecret {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.as_bytes().ct_eq(other.as_bytes())
+    }
+}
+impl PartialEq for SharedSecret {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+impl Eq for SharedSecret {}
+
 /// KEM trait
 pub trait Kem {
+    /// Primary liboqs method name (e.g. `"ML-KEM-768"`).
+    const NAME: &'static str;
+    /// Public key length in bytes.
+    const PUBLIC_KEY_LEN: usize;
+    /// Secret key length in bytes.
+    const SECRET_KEY_LEN: usize;
+    /// Ciphertext length in bytes.
+    const CIPHERTEXT_LEN: usize;
+    /// Shared secret length in bytes.
+    const SHARED_SECRET_LEN: usize;
+
     fn keypair() -> Result<(PublicKey, SecretKey), OqsError>;
     fn encapsulate(pk: &PublicKey) -> Result<(Ciphertext, SharedSecret), OqsError>;
+    /// Recovers the shared secret from `ct`.
+    ///
+    /// Errors only on wrong lengths, undecodable classical parts (e.g. an
+    /// invalid P-256 point) or a backend failure. A well-sized ciphertext
+    /// that was tampered with, or made for another key, is *implicitly
+    /// rejected* by ML-KEM, FrodoKEM, BIKE, NTRU, NTRU Prime and Classic
+    /// McEliece: the result is `Ok` with a pseudorandom secret that will
+    /// not match the sender's, so failures surface at the next step (e.g.
+    /// AEAD decryption). Tests can make this visible with
+    /// [`decapsulate_outcome`].
     fn decapsulate(ct: &Ciphertext, sk: &SecretKey) -> Result<SharedSecret, OqsError>;
+
+    /// [`keypair`](Self::keypair) drawing its randomness from `rng`
+    /// (see [`crate::rng`]).
+    fn keypair_with_rng(
+        rng: &mut (impl CryptoRngCore + Send),
+    ) -> Result<(PublicKey, SecretKey), OqsError> {
+        crate::rng::with_rng(rng, Self::keypair)
+    }
+
+    /// [`encapsulate`](Self::encapsulate) drawing its randomness from `rng`
+    /// (see [`crate::rng`]).
+    fn encapsulate_with_rng(
+        pk: &PublicKey,
+        rng: &mut (impl CryptoRngCore + Send),
+    ) -> Result<(Ciphertext, SharedSecret), OqsError> {
+        crate::rng::with_rng(rng, || Self::encapsulate(pk))
+    }
+}
+
+/// Object-safe, instance-based view of a [`Kem`].
+///
+/// Every `Kem` implements it, so algorithms can be chosen at runtime or
+/// injected as `&dyn DynKem` / `Box<dyn DynKem>`, and test doubles can
+/// implement it directly. Method names differ from [`Kem`]'s so both traits
+/// can be in scope at once.
+pub trait DynKem: Send + Sync {
+    /// See [`Kem::NAME`].
+    fn name(&self) -> &'static str;
+    /// See [`Kem::PUBLIC_KEY_LEN`].
+    fn public_key_len(&self) -> usize;
+    /// See [`Kem::SECRET_KEY_LEN`].
+    fn secret_key_len(&self) -> usize;
+    /// See [`Kem::CIPHERTEXT_LEN`].
+    fn ciphertext_len(&self) -> usize;
+    /// See [`Kem::SHARED_SECRET_LEN`].
+    fn shared_secret_len(&self) -> usize;
+
+    /// See [`Kem::keypair`].
+    fn generate_keypair(&self) -> Result<(PublicKey, SecretKey), OqsError>;
+    /// See [`Kem::encapsulate`].
+    fn encaps(&self, pk: &PublicKey) -> Result<(Ciphertext, SharedSecret), OqsError>;
+    /// See [`Kem::decapsulate`].
+    fn decaps(&self, ct: &Ciphertext, sk: &SecretKey) -> Result<SharedSecret, OqsError>;
+
+    /// [`generate_keypair`](Self::generate_keypair) drawing its randomness
+    /// from `rng` (see [`crate::rng`]).
+    fn generate_keypair_with_rng(
+        &self,
+        rng: &mut (dyn CryptoRngCore + Send),
+    ) -> Result<(PublicKey, SecretKey), OqsError> {
+        crate::rng::with_rng(rng, || self.generate_keypair())
+    }
+
+    /// [`encaps`](Self::encaps) drawing its randomness from `rng` (see
+    /// [`crate::rng`]).
+    fn encaps_with_rng(
+        &self,
+        pk: &PublicKey,
+        rng: &mut (dyn CryptoRngCore + Send),
+    ) -> Result<(Ciphertext, SharedSecret), OqsError> {
+        crate::rng::with_rng(rng, || self.encaps(pk))
+    }
+}
+
+impl<K: Kem + Send + Sync> DynKem for K {
+    fn name(&self) -> &'static str {
+        K::NAME
+    }
+    fn public_key_len(&self) -> usize {
+        K::PUBLIC_KEY_LEN
+    }
+    fn secret_key_len(&self) -> usize {
+        K::SECRET_KEY_LEN
+    }
+    fn ciphertext_len(&self) -> usize {
+        K::CIPHERTEXT_LEN
+    }
+    fn shared_secret_len(&self) -> usize {
+        K::SHARED_SECRET_LEN
+    }
+
+    fn generate_keypair(&self) -> Result<(PublicKey, SecretKey), OqsError> {
+        K::keypair()
+    }
+    fn encaps(&self, pk: &PublicKey) -> Result<(Ciphertext, SharedSecret), OqsError> {
+        K::encapsulate(pk)
+    }
+    fn decaps(&self, ct: &Ciphertext, sk: &SecretKey) -> Result<SharedSecret, OqsError> {
+        K::decapsulate(ct, sk)
+    }
 }
 
-/// Kyber768 implementation
-pub struct Kyber768;
+/// Stack size used by [`keypair_on_large_stack`] (64 MiB).
+pub const LARGE_STACK_SIZE: usize = 64 * 1024 * 1024;
+
+/// Run `K::keypair` on a dedicated thread with a [`LARGE_STACK_SIZE`] stack.
+///
+/// Classic McEliece key generation keeps its working matrices on the stack,
+/// which can overflow the default stack of spawned (and some main) threads.
+pub fn keypair_on_large_stack<K: Kem>() -> Result<(PublicKey, SecretKey), OqsError> {
+    keypair_with_stack_size::<K>(LARGE_STACK_SIZE)
+}
+
+/// Run `K::keypair` on a dedicated thread with `stack_size` bytes of stack.
+pub fn keypair_with_stack_size<K: Kem>(
+    stack_size: usize,
+) -> Result<(PublicKey, SecretKey), OqsError> {
+    // A scoped RNG from `rng::with_rng` follows the work; it comes back
+    // when `_restore` drops, after the scope has joined the thread.
+    let (loan, _restore) = crate::rng::lend();
+    std::thread::scope(|scope| {
+        std::thread::Builder::new()
+            .name("oqs-safe-keypair".into())
+            .stack_size(stack_size)
+            .spawn_scoped(scope, move || loan.run(K::keypair))
+            .map_err(|_| OqsError::Internal("keypair thread spawn"))?
+            .join()
+            .map_err(|_| OqsError::Internal("keypair thread panicked"))?
+    })
+}
+
+/// A decapsulation result classified against the encapsulator's secret.
+///
+/// Decapsulation itself cannot tell whether it implicitly rejected the
+/// ciphertext (see [`Kem::decapsulate`]); this type is for tests and
+/// diagnostics that know the expected secret.
+#[derive(Debug)]
+pub enum DecapsOutcome {
+    /// The secret matches the encapsulator's.
+    Matched(SharedSecret),
+    /// Decapsulation succeeded but produced a different secret: the
+    /// ciphertext was altered or made for another key.
+    ImplicitlyRejected(SharedSecret),
+}
+
+impl DecapsOutcome {
+    /// `true` for [`DecapsOutcome::ImplicitlyRejected`].
+    pub fn is_rejected(&self) -> bool {
+        matches!(self, DecapsOutcome::ImplicitlyRejected(_))
+    }
 
-impl Kem for Kyber768 {
-    fn keypair() -> Result<(PublicKey, SecretKey), OqsError> {
-        #[cfg(feature = "liboqs")]
-        {
-            let (pk, sk) = crate::ffi::kyber768_keypair()?;
-            Ok((PublicKey(pk), SecretKey(sk)))
+    /// The decapsulated secret, whichever the outcome.
+    pub fn into_secret(self) -> SharedSecret {
+        match self {
+            DecapsOutcome::Matched(ss) | DecapsOutcome::ImplicitlyRejected(ss) => ss,
         }
-        #[cfg(not(feature = "liboqs"))]
-        {
-            // Mock path: size-faithful random buffers for CI / no-liboqs environments.
-            let mut pk = vec![0u8; 1184]; // Kyber768 pk size
-            let mut sk = vec![0u8; 2400]; // Kyber768 sk size
-            OsRng.fill_bytes(&mut pk);
-            OsRng.fill_bytes(&mut sk);
-            Ok((PublicKey(pk), SecretKey(sk)))
+    }
+}
+
+/// Decapsulates `ct` with `K` and compares the secret to `expected` in
+/// constant time.
+pub fn decapsulate_outcome<K: Kem>(
+    ct: &Ciphertext,
+    sk: &SecretKey,
+    expected: &SharedSecret,
+) -> Result<DecapsOutcome, OqsError> {
+    let ss = K::decapsulate(ct, sk)?;
+    Ok(if ss == *expected {
+        DecapsOutcome::Matched(ss)
+    } else {
+        DecapsOutcome::ImplicitlyRejected(ss)
+    })
+}
+
+#[cfg(feature = "seal")]
+mod envelope;
+
+#[cfg(feature = "seal")]
+#[cfg_attr(docsrs, doc(cfg(feature = "seal")))]
+pub use envelope::{envelope_algorithm, open, seal};
+
+#[cfg(feature = "kem")]
+mod rustcrypto;
+
+#[cfg(feature = "kem")]
+#[cfg_attr(docsrs, doc(cfg(feature = "kem")))]
+pub use rustcrypto::{DecapsulationKey, EncapsulationKey};
+
+// ---- Mock backend helpers (size-faithful buffers; NOT cryptography) ----
+//
+// Mock keys share a tag (`pk[..32] == sk[..32]`) and the shared secret is the
+// whole ciphertext folded onto it, so decapsulation agrees with
+// encapsulation and a tampered ciphertext or wrong key yields a different
+// secret. This keeps protocol-level round trips testable without liboqs.
+
+#[cfg(not(feature = "liboqs"))]
+const MOCK_TAG_LEN: usize = 32;
+
+#[cfg(not(feature = "liboqs"))]
+fn mock_keypair_with<K: Kem>(fill: &mut dyn FnMut(&mut [u8])) -> (PublicKey, SecretKey) {
+    let mut pk = vec![0u8; K::PUBLIC_KEY_LEN];
+    let mut sk = SecretBuf::zeroed(K::SECRET_KEY_LEN);
+    fill(&mut pk);
+    fill(&mut sk[..]);
+    sk[..MOCK_TAG_LEN].copy_from_slice(&pk[..MOCK_TAG_LEN]);
+    (PublicKey(pk.into()), SecretKey(sk))
+}
+
+#[cfg(not(feature = "liboqs"))]
+fn mock_encapsulate_with<K: Kem>(
+    pk: &PublicKey,
+    fill: &mut dyn FnMut(&mut [u8]),
+) -> Result<(Ciphertext, SharedSecret), OqsError> {
+    if pk.len() != K::PUBLIC_KEY_LEN {
+        return Err(OqsError::InvalidLength);
+    }
+    let mut ct = vec![0u8; K::CIPHERTEXT_LEN];
+    fill(&mut ct);
+    let ss = mock_shared_secret::<K>(&pk.as_bytes()[..MOCK_TAG_LEN], &ct);
+    Ok((Ciphertext(ct), ss))
+}
+
+#[cfg(not(feature = "liboqs"))]
+fn mock_shared_secret<K: Kem>(tag: &[u8], ct: &[u8]) -> SharedSecret {
+    let mut ss = SecretBuf::zeroed(K::SHARED_SECRET_LEN);
+    for (i, b) in ss.iter_mut().enumerate() {
+        *b = tag[i % MOCK_TAG_LEN];
+    }
+    for (i, c) in ct.iter().enumerate() {
+        ss[i % K::SHARED_SECRET_LEN] ^= c;
+    }
+    SharedSecret(ss)
+}
+
+/// Deterministic stand-in for a DRBG seeded with `seed` (mock derand paths).
+#[cfg(all(not(feature = "liboqs"), any(feature = "xwing", feature = "testing")))]
+fn mock_expand(seed: &[u8]) -> impl FnMut(&mut [u8]) + '_ {
+    let mut counter = 0usize;
+    move |buf: &mut [u8]| {
+        for b in buf.iter_mut() {
+            *b = seed[counter % seed.len()] ^ (counter / seed.len()) as u8;
+            counter += 1;
         }
     }
+}
+
+#[cfg(not(feature = "liboqs"))]
+fn mock_keypair<K: Kem>() -> Result<(PublicKey, SecretKey), OqsError> {
+    Ok(mock_keypair_with::<K>(&mut crate::rng::fill_bytes))
+}
+
+#[cfg(not(feature = "liboqs"))]
+fn mock_encapsulate<K: Kem>(pk: &PublicKey) -> Result<(Ciphertext, SharedSecret), OqsError> {
+    mock_encapsulate_with::<K>(pk, &mut crate::rng::fill_bytes)
+}
+
+#[cfg(not(feature = "liboqs"))]
+fn mock_decapsulate<K: Kem>(ct: &Ciphertext, sk: &SecretKey) -> Result<SharedSecret, OqsError> {
+    if ct.len() != K::CIPHERTEXT_LEN || sk.len() != K::SECRET_KEY_LEN {
+        return Err(OqsError::InvalidLength);
+    }
+    Ok(mock_shared_secret::<K>(
+        &sk.as_bytes()[..MOCK_TAG_LEN],
+        ct.as_bytes(),
+    ))
+}
 
-    fn encapsulate(pk: &PublicKey) -> Result<(Ciphertext, SharedSecret), OqsError> {
-        #[cfg(feature = "liboqs")]
-        {
-            crate::ffi::kyber768_encapsulate(pk.as_bytes())
-                .map(|(c, s)| (Ciphertext(c), SharedSecret(s)))
+/// Defines zero-sized KEM types backed by liboqs method names (tried in
+/// order, to absorb upstream renames) with the mock backend as fallback.
+macro_rules! oqs_kem {
+    ($(
+        $(#[$attr:meta])*
+        $name:ident {
+            names: [$primary:literal $(, $fallback:literal)* $(,)?],
+            public_key: $pk:expr,
+            secret_key: $sk:expr,
+            ciphertext: $ct:expr,
+            shared_secret: $ss:expr $(,)?
         }
-        #[cfg(not(feature = "liboqs"))]
-        {
-            if pk.len() != 1184 {
-                return Err(OqsError::InvalidLength);
+    )*) => {$(
+        $(#[$attr])*
+        pub struct $name;
+
+        impl Kem for $name {
+            const NAME: &'static str = $primary;
+            const PUBLIC_KEY_LEN: usize = $pk;
+            const SECRET_KEY_LEN: usize = $sk;
+            const CIPHERTEXT_LEN: usize = $ct;
+            const SHARED_SECRET_LEN: usize = $ss;
+
+            fn keypair() -> Result<(PublicKey, SecretKey), OqsError> {
+                #[cfg(feature = "liboqs")]
+                {
+                    let (pk, sk) = crate::ffi::kem_keypair(&[$primary $(, $fallback)*])?;
+                    Ok((PublicKey(pk.into()), SecretKey(sk)))
+                }
+                #[cfg(not(feature = "liboqs"))]
+                {
+                    mock_keypair::<Self>()
+                }
+            }
+
+            fn encapsulate(pk: &PublicKey) -> Result<(Ciphertext, SharedSecret), OqsError> {
+                #[cfg(feature = "liboqs")]
+                {
+                    crate::ffi::kem_encapsulate(&[$primary $(, $fallback)*], pk.as_bytes())
+                        .map(|(c, s)| (Ciphertext(c), SharedSecret(s)))
+                }
+                #[cfg(not(feature = "liboqs"))]
+                {
+                    mock_encapsulate::<Self>(pk)
+                }
+            }
+
+            fn decapsulate(ct: &Ciphertext, sk: &SecretKey) -> Result<SharedSecret, OqsError> {
+                #[cfg(feature = "liboqs")]
+                {
+                    crate::ffi::kem_decapsulate(
+                        &[$primary $(, $fallback)*],
+                        ct.as_bytes(),
+                        sk.as_bytes(),
+                    )
+                    .map(SharedSecret)
+                }
+                #[cfg(not(feature = "liboqs"))]
+                {
+                    mock_decapsulate::<Self>(ct, sk)
+                }
             }
-            let mut ct = vec![0u8; 1088]; // Kyber768 ct size
-            let mut ss = vec![0u8; 32]; // Shared secret size (bytes)
-            OsRng.fill_bytes(&mut ct);
-            OsRng.fill_bytes(&mut ss);
-            Ok((Ciphertext(ct), SharedSecret(ss)))
         }
+    )*};
+}
+
+oqs_kem! {
+    /// Kyber768 implementation
+    Kyber768 {
+        names: ["ML-KEM-768", "Kyber768"],
+        public_key: 1184,
+        secret_key: 2400,
+        ciphertext: 1088,
+        shared_secret: 32,
     }
+    /// Kyber1024 / ML-KEM-1024 (FIPS 203)
+    Kyber1024 {
+        names: ["ML-KEM-1024", "Kyber1024"],
+        public_key: 1568,
+        secret_key: 3168,
+        ciphertext: 1568,
+        shared_secret: 32,
+    }
+}
 
-    fn decapsulate(ct: &Ciphertext, sk: &SecretKey) -> Result<SharedSecret, OqsError> {
-        #[cfg(feature = "liboqs")]
-        {
-            crate::ffi::kyber768_decapsulate(ct.as_bytes(), sk.as_bytes()).map(SharedSecret)
-        }
-        #[cfg(not(feature = "liboqs"))]
-        {
-            if ct.len() != 1088 || sk.len() != 2400 {
-                return Err(OqsError::InvalidLength);
+/// FIPS 203 internal (derandomized) ML-KEM entry points. X-Wing builds on
+/// them; under `testing` they are public so ACVP vectors can drive them.
+#[cfg(any(feature = "xwing", feature = "testing"))]
+macro_rules! ml_kem_derand {
+    ($vis:vis $($name:ident => $alg:literal),* $(,)?) => {$(
+        impl $name {
+            /// FIPS 203 `ML-KEM.KeyGen_internal(d, z)` with `seed = d || z`.
+            ///
+            /// The seed must come from a CSPRNG; [`Kem::keypair`] does that.
+            $vis fn keypair_derand(seed: &[u8; 64]) -> Result<(PublicKey, SecretKey), OqsError> {
+                #[cfg(feature = "liboqs")]
+                {
+                    let (pk, sk) = crate::ffi::kem_keypair_derand(&[$alg], seed)?;
+                    Ok((PublicKey(pk.into()), SecretKey(sk)))
+                }
+                #[cfg(not(feature = "liboqs"))]
+                {
+                    Ok(mock_keypair_with::<Self>(&mut mock_expand(seed)))
+                }
+            }
+
+            /// FIPS 203 `ML-KEM.Encaps_internal(ek, m)`.
+            ///
+            /// `m` must come from a CSPRNG; [`Kem::encapsulate`] does that.
+            $vis fn encapsulate_derand(
+                pk: &PublicKey,
+                m: &[u8; 32],
+            ) -> Result<(Ciphertext, SharedSecret), OqsError> {
+                #[cfg(feature = "liboqs")]
+                {
+                    crate::ffi::kem_encapsulate_derand(&[$alg], pk.as_bytes(), m)
+                        .map(|(c, s)| (Ciphertext(c), SharedSecret(s)))
+                }
+                #[cfg(not(feature = "liboqs"))]
+                {
+                    mock_encapsulate_with::<Self>(pk, &mut mock_expand(m))
+                }
             }
-            let mut ss = vec![0u8; 32];
-            OsRng.fill_bytes(&mut ss);
-            Ok(SharedSecret(ss))
         }
+    )*};
+}
+
+#[cfg(feature = "testing")]
+ml_kem_derand!(pub Kyber768 => "ML-KEM-768", Kyber1024 => "ML-KEM-1024");
+
+#[cfg(all(feature = "xwing", not(feature = "testing")))]
+ml_kem_derand!(pub(crate) Kyber768 => "ML-KEM-768");
+
+// ---- Classic McEliece (code-based; very large public keys) ----
+
+#[cfg(feature = "mceliece")]
+oqs_kem! {
+    /// Classic-McEliece-348864 (NIST level 1)
+    #[cfg_attr(docsrs, doc(cfg(feature = "mceliece")))]
+    ClassicMcEliece348864 {
+        names: ["Classic-McEliece-348864"],
+        public_key: 261_120,
+        secret_key: 6492,
+        ciphertext: 96,
+        shared_secret: 32,
+    }
+    /// Classic-McEliece-348864f (NIST level 1, fast keygen)
+    #[cfg_attr(docsrs, doc(cfg(feature = "mceliece")))]
+    ClassicMcEliece348864f {
+        names: ["Classic-McEliece-348864f"],
+        public_key: 261_120,
+        secret_key: 6492,
+        ciphertext: 96,
+        shared_secret: 32,
+    }
+    /// Classic-McEliece-460896 (NIST level 3)
+    #[cfg_attr(docsrs, doc(cfg(feature = "mceliece")))]
+    ClassicMcEliece460896 {
+        names: ["Classic-McEliece-460896"],
+        public_key: 524_160,
+        secret_key: 13_608,
+        ciphertext: 156,
+        shared_secret: 32,
+    }
+    /// Classic-McEliece-460896f (NIST level 3, fast keygen)
+    #[cfg_attr(docsrs, doc(cfg(feature = "mceliece")))]
+    ClassicMcEliece460896f {
+        names: ["Classic-McEliece-460896f"],
+        public_key: 524_160,
+        secret_key: 13_608,
+        ciphertext: 156,
+        shared_secret: 32,
+    }
+    /// Classic-McEliece-6688128 (NIST level 5)
+    #[cfg_attr(docsrs, doc(cfg(feature = "mceliece")))]
+    ClassicMcEliece6688128 {
+        names: ["Classic-McEliece-6688128"],
+        public_key: 1_044_992,
+        secret_key: 13_932,
+        ciphertext: 208,
+        shared_secret: 32,
+    }
+    /// Classic-McEliece-6688128f (NIST level 5, fast keygen)
+    #[cfg_attr(docsrs, doc(cfg(feature = "mceliece")))]
+    ClassicMcEliece6688128f {
+        names: ["Classic-McEliece-6688128f"],
+        public_key: 1_044_992,
+        secret_key: 13_932,
+        ciphertext: 208,
+        shared_secret: 32,
+    }
+    /// Classic-McEliece-6960119 (NIST level 5)
+    #[cfg_attr(docsrs, doc(cfg(feature = "mceliece")))]
+    ClassicMcEliece6960119 {
+        names: ["Classic-McEliece-6960119"],
+        public_key: 1_047_319,
+        secret_key: 13_948,
+        ciphertext: 194,
+        shared_secret: 32,
+    }
+    /// Classic-McEliece-6960119f (NIST level 5, fast keygen)
+    #[cfg_attr(docsrs, doc(cfg(feature = "mceliece")))]
+    ClassicMcEliece6960119f {
+        names: ["Classic-McEliece-6960119f"],
+        public_key: 1_047_319,
+        secret_key: 13_948,
+        ciphertext: 194,
+        shared_secret: 32,
+    }
+    /// Classic-McEliece-8192128 (NIST level 5)
+    #[cfg_attr(docsrs, doc(cfg(feature = "mceliece")))]
+    ClassicMcEliece8192128 {
+        names: ["Classic-McEliece-8192128"],
+        public_key: 1_357_824,
+        secret_key: 14_120,
+        ciphertext: 208,
+        shared_secret: 32,
+    }
+    /// Classic-McEliece-8192128f (NIST level 5, fast keygen)
+    #[cfg_attr(docsrs, doc(cfg(feature = "mceliece")))]
+    ClassicMcEliece8192128f {
+        names: ["Classic-McEliece-8192128f"],
+        public_key: 1_357_824,
+        secret_key: 14_120,
+        ciphertext: 208,
+        shared_secret: 32,
+    }
+}
+
+// ---- FrodoKEM (unstructured lattices; conservative choice) ----
+
+#[cfg(feature = "frodokem")]
+oqs_kem! {
+    /// FrodoKEM-640-AES (NIST level 1)
+    #[cfg_attr(docsrs, doc(cfg(feature = "frodokem")))]
+    FrodoKem640Aes {
+        names: ["FrodoKEM-640-AES"],
+        public_key: 9_616,
+        secret_key: 19_888,
+        ciphertext: 9_720,
+        shared_secret: 16,
+    }
+    /// FrodoKEM-640-SHAKE (NIST level 1)
+    #[cfg_attr(docsrs, doc(cfg(feature = "frodokem")))]
+    FrodoKem640Shake {
+        names: ["FrodoKEM-640-SHAKE"],
+        public_key: 9_616,
+        secret_key: 19_888,
+        ciphertext: 9_720,
+        shared_secret: 16,
+    }
+    /// FrodoKEM-976-AES (NIST level 3)
+    #[cfg_attr(docsrs, doc(cfg(feature = "frodokem")))]
+    FrodoKem976Aes {
+        names: ["FrodoKEM-976-AES"],
+        public_key: 15_632,
+        secret_key: 31_296,
+        ciphertext: 15_744,
+        shared_secret: 24,
+    }
+    /// FrodoKEM-976-SHAKE (NIST level 3)
+    #[cfg_attr(docsrs, doc(cfg(feature = "frodokem")))]
+    FrodoKem976Shake {
+        names: ["FrodoKEM-976-SHAKE"],
+        public_key: 15_632,
+        secret_key: 31_296,
+        ciphertext: 15_744,
+        shared_secret: 24,
+    }
+    /// FrodoKEM-1344-AES (NIST level 5)
+    #[cfg_attr(docsrs, doc(cfg(feature = "frodokem")))]
+    FrodoKem1344Aes {
+        names: ["FrodoKEM-1344-AES"],
+        public_key: 21_520,
+        secret_key: 43_088,
+        ciphertext: 21_632,
+        shared_secret: 32,
+    }
+    /// FrodoKEM-1344-SHAKE (NIST level 5)
+    #[cfg_attr(docsrs, doc(cfg(feature = "frodokem")))]
+    FrodoKem1344Shake {
+        names: ["FrodoKEM-1344-SHAKE"],
+        public_key: 21_520,
+        secret_key: 43_088,
+        ciphertext: 21_632,
+        shared_secret: 32,
+    }
+}
+
+// ---- BIKE (QC-MDPC code-based; runtime AVX2 dispatch, see `backend_info`) ----
+
+#[cfg(feature = "bike")]
+oqs_kem! {
+    /// BIKE-L1 (NIST level 1)
+    ///
+    /// Decapsulation always succeeds and rejects implicitly: a decoding
+    /// failure (rare, about 2^-128 per ciphertext) yields a shared secret
+    /// that does not match the sender's.
+    #[cfg_attr(docsrs, doc(cfg(feature = "bike")))]
+    BikeL1 {
+        names: ["BIKE-L1"],
+        public_key: 1_541,
+        secret_key: 5_223,
+        ciphertext: 1_573,
+        shared_secret: 32,
+    }
+    /// BIKE-L3 (NIST level 3)
+    ///
+    /// Decapsulation always succeeds and rejects implicitly: a decoding
+    /// failure (rare, about 2^-192 per ciphertext) yields a shared secret
+    /// that does not match the sender's.
+    #[cfg_attr(docsrs, doc(cfg(feature = "bike")))]
+    BikeL3 {
+        names: ["BIKE-L3"],
+        public_key: 3_083,
+        secret_key: 10_105,
+        ciphertext: 3_115,
+        shared_secret: 32,
+    }
+    /// BIKE-L5 (NIST level 5)
+    ///
+    /// Decapsulation always succeeds and rejects implicitly: a decoding
+    /// failure (rare, about 2^-256 per ciphertext) yields a shared secret
+    /// that does not match the sender's.
+    #[cfg_attr(docsrs, doc(cfg(feature = "bike")))]
+    BikeL5 {
+        names: ["BIKE-L5"],
+        public_key: 5_122,
+        secret_key: 16_494,
+        ciphertext: 5_154,
+        shared_secret: 32,
+    }
+}
+
+// ---- NTRU (HPS / HRSS) ----
+
+#[cfg(feature = "ntru")]
+oqs_kem! {
+    /// NTRU-HPS-2048-509 (NIST level 1)
+    #[cfg_attr(docsrs, doc(cfg(feature = "ntru")))]
+    NtruHps2048509 {
+        names: ["NTRU-HPS-2048-509"],
+        public_key: 699,
+        secret_key: 935,
+        ciphertext: 699,
+        shared_secret: 32,
+    }
+    /// NTRU-HPS-2048-677 (NIST level 3)
+    #[cfg_attr(docsrs, doc(cfg(feature = "ntru")))]
+    NtruHps2048677 {
+        names: ["NTRU-HPS-2048-677"],
+        public_key: 930,
+        secret_key: 1234,
+        ciphertext: 930,
+        shared_secret: 32,
+    }
+    /// NTRU-HPS-4096-821 (NIST level 5)
+    #[cfg_attr(docsrs, doc(cfg(feature = "ntru")))]
+    NtruHps4096821 {
+        names: ["NTRU-HPS-4096-821"],
+        public_key: 1230,
+        secret_key: 1590,
+        ciphertext: 1230,
+        shared_secret: 32,
+    }
+    /// NTRU-HPS-4096-1229 (NIST level 5)
+    #[cfg_attr(docsrs, doc(cfg(feature = "ntru")))]
+    NtruHps40961229 {
+        names: ["NTRU-HPS-4096-1229"],
+        public_key: 1842,
+        secret_key: 2366,
+        ciphertext: 1842,
+        shared_secret: 32,
+    }
+    /// NTRU-HRSS-701 (NIST level 3)
+    #[cfg_attr(docsrs, doc(cfg(feature = "ntru")))]
+    NtruHrss701 {
+        names: ["NTRU-HRSS-701"],
+        public_key: 1138,
+        secret_key: 1450,
+        ciphertext: 1138,
+        shared_secret: 32,
+    }
+    /// NTRU-HRSS-1373 (NIST level 5)
+    #[cfg_attr(docsrs, doc(cfg(feature = "ntru")))]
+    NtruHrss1373 {
+        names: ["NTRU-HRSS-1373"],
+        public_key: 2401,
+        secret_key: 2983,
+        ciphertext: 2401,
+        shared_secret: 32,
+    }
+}
+
+// ---- Streamlined NTRU Prime ----
+
+#[cfg(feature = "ntruprime")]
+oqs_kem! {
+    /// sntrup761 (NIST level 2), the PQ half of OpenSSH's
+    /// `sntrup761x25519-sha512` key exchange.
+    #[cfg_attr(docsrs, doc(cfg(feature = "ntruprime")))]
+    Sntrup761 {
+        names: ["sntrup761"],
+        public_key: 1158,
+        secret_key: 1763,
+        ciphertext: 1039,
+        shared_secret: 32,
     }
 }
 
@@ -159,14 +820,14 @@ impl Kem for Kyber768 {
 impl PublicKey {
     /// Construct without size checks (tests only).
     pub fn from_bytes_unchecked(bytes: Vec<u8>) -> Self {
-        Self(bytes)
+        Self(bytes.into())
     }
 }
 #[cfg(feature = "testing")]
 impl SecretKey {
     /// Construct without size checks (tests only).
     pub fn from_bytes_unchecked(bytes: Vec<u8>) -> Self {
-        Self(bytes)
+        Self(bytes.into())
     }
 }
 #[cfg(feature = "testing")]
@@ -176,3 +837,10 @@ impl Ciphertext {
         Self(bytes)
     }
 }
+#[cfg(feature = "testing")]
+impl SharedSecret {
+    /// Construct without size checks (tests only).
+    pub fn from_bytes_unchecked(bytes: Vec<u8>) -> Self {
+        Self(bytes.into())
+    }
+}
@@ -0,0 +1,149 @@
+// Copyright (c) 2025 Orlando Trajano
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! KEM-DEM public-key encryption with a versioned, self-describing envelope.
+//!
+//! # Envelope (version 1)
+//!
+//! | field       | bytes               | notes                                  |
+//! |-------------|---------------------|----------------------------------------|
+//! | magic       | 4                   | `"OQSE"`                               |
+//! | version     | 1                   | `0x01`                                 |
+//! | alg length  | 1                   |                                        |
+//! | algorithm   | alg length          | `Kem::NAME`, UTF-8                     |
+//! | aead id     | 2 (big endian)      | `0x0003` = ChaCha20-Poly1305           |
+//! | pk id       | 32                  | `SHA-256(recipient public key)`        |
+//! | kem ct      | `K::CIPHERTEXT_LEN` |                                        |
+//! | aead ct     | rest                | ciphertext `\|\|` 16-byte tag          |
+//!
+//! Everything before the AEAD ciphertext is the *header*. The AEAD key and
+//! nonce are `HKDF-SHA256(ikm = shared secret, info = LABEL || header)`, so
+//! they are bound to the algorithm, the KEM ciphertext and the recipient's
+//! public key; the header is additionally authenticated as AEAD associated
+//! data, followed by the caller's `aad`.
+
+use super::{Ciphertext, Kem, PublicKey, SecretKey};
+use crate::OqsError;
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::ChaCha20Poly1305;
+use hkdf::Hkdf;
+use sha2::{Digest, Sha256};
+use zeroize::Zeroizing;
+
+const MAGIC: &[u8; 4] = b"OQSE";
+const VERSION: u8 = 1;
+const AEAD_CHACHA20POLY1305: u16 = 0x0003;
+const PK_ID_LEN: usize = 32;
+const TAG_LEN: usize = 16;
+const LABEL: &[u8] = b"oqs-safe kem-dem v1";
+
+/// Parsed fixed-position header fields.
+struct Header<'a> {
+    algorithm: &'a str,
+    aead_id: u16,
+    /// Offset of the KEM ciphertext.
+    kem_ct_at: usize,
+}
+
+fn parse_header(envelope: &[u8]) -> Result<Header<'_>, OqsError> {
+    if envelope.len() < MAGIC.len() + 2 || &envelope[..4] != MAGIC {
+        return Err(OqsError::InvalidEncoding("envelope magic"));
+    }
+    if envelope[4] != VERSION {
+        return Err(OqsError::InvalidEncoding("envelope version"));
+    }
+    let alg_end = 6 + usize::from(envelope[5]);
+    let kem_ct_at = alg_end + 2 + PK_ID_LEN;
+    if envelope.len() < kem_ct_at {
+        return Err(OqsError::InvalidLength);
+    }
+    let algorithm = core::str::from_utf8(&envelope[6..alg_end])
+        .map_err(|_| OqsError::InvalidEncoding("envelope algorithm"))?;
+    let aead_id = u16::from_be_bytes([envelope[alg_end], envelope[alg_end + 1]]);
+    Ok(Header {
+        algorithm,
+        aead_id,
+        kem_ct_at,
+    })
+}
+
+/// Algorithm name recorded in an envelope, e.g. to pick the key to open it with.
+pub fn envelope_algorithm(envelope: &[u8]) -> Result<&str, OqsError> {
+    parse_header(envelope).map(|h| h.algorithm)
+}
+
+fn derive_key_nonce(ss: &[u8], header: &[u8]) -> Result<Zeroizing<[u8; 44]>, OqsError> {
+    let mut okm = Zeroizing::new([0u8; 44]);
+    Hkdf::<Sha256>::new(None, ss)
+        .expand_multi_info(&[LABEL, header], &mut okm[..])
+        .map_err(|_| OqsError::Internal("hkdf expand"))?;
+    Ok(okm)
+}
+
+fn cipher(okm: &[u8; 44]) -> Result<ChaCha20Poly1305, OqsError> {
+    ChaCha20Poly1305::new_from_slice(&okm[..32]).map_err(|_| OqsError::Internal("aead key"))
+}
+
+/// Encrypts `plaintext` to `pk` under KEM `K`; returns a version-1 envelope.
+pub fn seal<K: Kem>(pk: &PublicKey, plaintext: &[u8], aad: &[u8]) -> Result<Vec<u8>, OqsError> {
+    if pk.len() != K::PUBLIC_KEY_LEN {
+        return Err(OqsError::InvalidLength);
+    }
+    let alg_len = u8::try_from(K::NAME.len()).map_err(|_| OqsError::Internal("algorithm name"))?;
+    let (ct, ss) = K::encapsulate(pk)?;
+
+    let mut out =
+        Vec::with_capacity(8 + K::NAME.len() + PK_ID_LEN + ct.len() + plaintext.len() + TAG_LEN);
+    out.extend_from_slice(MAGIC);
+    out.push(VERSION);
+    out.push(alg_len);
+    out.extend_from_slice(K::NAME.as_bytes());
+    out.extend_from_slice(&AEAD_CHACHA20POLY1305.to_be_bytes());
+    out.extend_from_slice(&Sha256::digest(pk.as_bytes()));
+    out.extend_from_slice(ct.as_bytes());
+
+    let okm = derive_key_nonce(ss.as_bytes(), &out)?;
+    let sealed = cipher(&okm)?
+        .encrypt(
+            okm[32..].into(),
+            Payload {
+                msg: plaintext,
+                aad: &[&out[..], aad].concat(),
+            },
+        )
+        .map_err(|_| OqsError::Internal("aead seal"))?;
+    out.extend_from_slice(&sealed);
+    Ok(out)
+}
+
+/// Decrypts an envelope produced by [`seal`] for the same `K` and `aad`.
+///
+/// Fails with `InvalidEncoding` if the envelope names another algorithm or
+/// format, and `VerifyFail` if it was not sealed to this key or was modified.
+pub fn open<K: Kem>(sk: &SecretKey, envelope: &[u8], aad: &[u8]) -> Result<Vec<u8>, OqsError> {
+    let header = parse_header(envelope)?;
+    if header.algorithm != K::NAME {
+        return Err(OqsError::InvalidEncoding("envelope algorithm"));
+    }
+    if header.aead_id != AEAD_CHACHA20POLY1305 {
+        return Err(OqsError::InvalidEncoding("envelope aead"));
+    }
+    let header_end = header.kem_ct_at + K::CIPHERTEXT_LEN;
+    if envelope.len() < header_end + TAG_LEN {
+        return Err(OqsError::InvalidLength);
+    }
+    let (head, sealed) = envelope.split_at(header_end);
+    let ct = Ciphertext(head[header.kem_ct_at..].to_vec());
+    let ss = K::decapsulate(&ct, sk)?;
+
+    let okm = derive_key_nonce(ss.as_bytes(), head)?;
+    cipher(&okm)?
+        .decrypt(
+            okm[32..].into(),
+            Payload {
+                msg: sealed,
+                aad: &[head, aad].concat(),
+            },
+        )
+        .map_err(|_| OqsError::VerifyFail)
+}
@@ -0,0 +1,165 @@
+// Copyright (c) 2025 Orlando Trajano
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! RustCrypto [`kem`](::kem) 0.3 adapters.
+//!
+//! | type                        | implements                                   |
+//! |-----------------------------|----------------------------------------------|
+//! | [`EncapsulationKey<K>`]     | `Encapsulate<Ciphertext, SharedSecret>`      |
+//! | [`DecapsulationKey<K>`]     | `Decapsulate<Ciphertext, SharedSecret>`      |
+//!
+//! `K` is any [`Kem`], so new algorithms are covered as they are added. Keys
+//! are length-checked against `K` on construction.
+//!
+//! `Encapsulate` hands over an RNG that need not be `Send`, which
+//! [`with_rng`](crate::rng::with_rng) requires; encapsulation therefore draws
+//! from a ChaCha20 stream seeded with 32 bytes from that RNG.
+
+use super::{Ciphertext, Kem, PublicKey, SecretKey, SharedSecret};
+use crate::OqsError;
+use core::fmt;
+use core::marker::PhantomData;
+use rand_chacha::ChaCha20Rng;
+use rand_core::{CryptoRngCore, SeedableRng};
+use zeroize::Zeroizing;
+
+/// Public half of a `K` key pair, usable wherever a [`kem::Encapsulate`](::kem::Encapsulate)
+/// is expected.
+pub struct EncapsulationKey<K> {
+    public: PublicKey,
+    _kem: PhantomData<K>,
+}
+
+/// Secret half of a `K` key pair, usable wherever a
+/// [`kem::Decapsulate`](::kem::Decapsulate) is expected.
+pub struct DecapsulationKey<K> {
+    secret: SecretKey,
+    encapsulation: EncapsulationKey<K>,
+}
+
+impl<K: Kem> DecapsulationKey<K> {
+    /// Generates a fresh key pair.
+    pub fn generate() -> Result<Self, OqsError> {
+        let (pk, sk) = K::keypair()?;
+        Self::from_keypair(pk, sk)
+    }
+
+    /// Wraps an existing key pair of the right lengths for `K`.
+    pub fn from_keypair(public: PublicKey, secret: SecretKey) -> Result<Self, OqsError> {
+        if secret.len() != K::SECRET_KEY_LEN {
+            return Err(OqsError::InvalidLength);
+        }
+        Ok(Self {
+            secret,
+            encapsulation: EncapsulationKey::new(public)?,
+        })
+    }
+
+    /// The matching encapsulation key.
+    pub fn encapsulation_key(&self) -> &EncapsulationKey<K> {
+        &self.encapsulation
+    }
+
+    /// The underlying secret key.
+    pub fn secret_key(&self) -> &SecretKey {
+        &self.secret
+    }
+}
+
+impl<K: Kem> EncapsulationKey<K> {
+    /// Wraps a public key of the right length for `K`.
+    pub fn new(public: PublicKey) -> Result<Self, OqsError> {
+        if public.len() != K::PUBLIC_KEY_LEN {
+            return Err(OqsError::InvalidLength);
+        }
+        Ok(Self {
+            public,
+            _kem: PhantomData,
+        })
+    }
+
+    /// The underlying public key.
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public
+    }
+}
+
+impl<K> Clone for DecapsulationKey<K> {
+    fn clone(&self) -> Self {
+        Self {
+            secret: self.secret.clone(),
+            encapsulation: self.encapsulation.clone(),
+        }
+    }
+}
+
+impl<K> Clone for EncapsulationKey<K> {
+    fn clone(&self) -> Self {
+        Self {
+            public: self.public.clone(),
+            _kem: PhantomData,
+        }
+    }
+}
+
+impl<K: Kem> fmt::Debug for DecapsulationKey<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DecapsulationKey")
+            .field("kem", &K::NAME)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<K: Kem> fmt::Debug for EncapsulationKey<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EncapsulationKey")
+            .field("kem", &K::NAME)
+            .field("public", &self.public)
+            .finish()
+    }
+}
+
+impl<K> PartialEq for EncapsulationKey<K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.public == other.public
+    }
+}
+
+impl<K> Eq for EncapsulationKey<K> {}
+
+impl<K> AsRef<[u8]> for EncapsulationKey<K> {
+    fn as_ref(&self) -> &[u8] {
+        self.public.as_bytes()
+    }
+}
+
+impl<K: Kem> TryFrom<&[u8]> for EncapsulationKey<K> {
+    type Error = OqsError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, OqsError> {
+        Self::new(PublicKey(bytes.into()))
+    }
+}
+
+impl<K: Kem> ::kem::Encapsulate<Ciphertext, SharedSecret> for EncapsulationKey<K> {
+    type Error = OqsError;
+
+    fn encapsulate(
+        &self,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<(Ciphertext, SharedSecret), OqsError> {
+        let mut seed = Zeroizing::new([0u8; 32]);
+        rng.try_fill_bytes(seed.as_mut())
+            .map_err(|_| OqsError::Internal("caller RNG failed"))?;
+        let mut stream = ChaCha20Rng::from_seed(*seed);
+        K::encapsulate_with_rng(&self.public, &mut stream)
+    }
+}
+
+impl<K: Kem> ::kem::Decapsulate<Ciphertext, SharedSecret> for DecapsulationKey<K> {
+    type Error = OqsError;
+
+    fn decapsulate(&self, encapsulated_key: &Ciphertext) -> Result<SharedSecret, OqsError> {
+        K::decapsulate(encapsulated_key, &self.secret)
+    }
+}
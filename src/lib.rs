@@ -9,13 +9,45 @@ compile_error!("No backend selected. Enable `features=[\"liboqs\"]` for real cry
 #[cfg(not(allow_mock_release))]
 compile_error!("`mock` backend in release build. Use RUSTFLAGS='--cfg allow_mock_release' if you truly intend to ship a mock.");
 
+pub mod backend;
 pub mod error;
 pub mod kem;
+pub mod rng;
 pub mod sig;
 
+#[cfg(feature = "composite")]
+#[cfg_attr(docsrs, doc(cfg(feature = "composite")))]
+pub mod composite;
+
+#[cfg(feature = "hpke")]
+#[cfg_attr(docsrs, doc(cfg(feature = "hpke")))]
+pub mod hpke;
+
+#[cfg(feature = "hybrid")]
+#[cfg_attr(docsrs, doc(cfg(feature = "hybrid")))]
+pub mod hybrid;
+
+#[cfg(feature = "noise")]
+#[cfg_attr(docsrs, doc(cfg(feature = "noise")))]
+pub mod noise;
+
+#[cfg(feature = "rustls")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rustls")))]
+pub mod rustls;
+
+#[cfg(feature = "tls")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tls")))]
+pub mod tls;
+
+#[cfg(feature = "xwing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "xwing")))]
+pub mod xwing;
+
 #[cfg(feature = "liboqs")]
 pub(crate) mod ffi;
 
+mod secret;
+
 #[cfg(feature = "selftest_at_startup")]
 mod selftest;
 
@@ -25,4 +57,5 @@ fn _oqs_safe_selftest() {
     let _ = std::panic::catch_unwind(|| selftest::check());
 }
 
+pub use backend::{backend_info, Backend, BackendInfo};
 pub use error::OqsError;
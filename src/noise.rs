@@ -0,0 +1,457 @@
+// Copyright (c) 2025 Orlando Trajano
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Post-quantum Noise (PQNoise) handshakes over any [`Kem`].
+//!
+//! Follows the Noise Protocol Framework (rev. 34) state machine with the
+//! Diffie-Hellman tokens replaced by KEM tokens, as in PQNoise
+//! (Angel et al., CCS 2022):
+//!
+//! | token  | sender                                                      |
+//! |--------|-------------------------------------------------------------|
+//! | `e`    | fresh ephemeral KEM keypair; writes `e.pk`, `MixHash(e.pk)`  |
+//! | `s`    | writes `EncryptAndHash(s.pk)`                               |
+//! | `ekem` | encapsulates to `re`; writes `EncryptAndHash(ct)`, `MixKey(ss)` |
+//! | `skem` | encapsulates to `rs`; writes `EncryptAndHash(ct)`, `MixKey(ss)` |
+//!
+//! The receiver of `ekem`/`skem` decapsulates with its ephemeral/static key.
+//! Cipher is always ChaChaPoly; the hash is [`Sha256`], [`Blake2s256`] or
+//! [`Blake2b512`]. The protocol name is
+//! `Noise_<pattern>_<Kem::NAME>_ChaChaPoly_<hash>`.
+
+use crate::kem::{Ciphertext, Kem, PublicKey, SecretKey};
+use crate::OqsError;
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::ChaCha20Poly1305;
+use core::marker::PhantomData;
+use hkdf::SimpleHkdf;
+use sha2::digest::core_api::BlockSizeUser;
+use sha2::Digest;
+use zeroize::Zeroizing;
+
+pub use blake2::{Blake2b512, Blake2s256};
+pub use sha2::Sha256;
+
+const TAG_LEN: usize = 16;
+
+/// Noise hash function (`HASH` / `HKDF` are derived from the digest).
+pub trait NoiseHash: Digest + BlockSizeUser + Clone {
+    /// Name used in the protocol name, e.g. `"SHA256"`.
+    const NAME: &'static str;
+}
+
+impl NoiseHash for Sha256 {
+    const NAME: &'static str = "SHA256";
+}
+
+impl NoiseHash for Blake2s256 {
+    const NAME: &'static str = "BLAKE2s";
+}
+
+impl NoiseHash for Blake2b512 {
+    const NAME: &'static str = "BLAKE2b";
+}
+
+/// Supported handshake patterns.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Pattern {
+    /// Responder static key known in advance; only the responder is authenticated.
+    ///
+    /// ```text
+    /// pqNK:
+    ///   <- s
+    ///   ...
+    ///   -> skem, e
+    ///   <- ekem
+    /// ```
+    PqNK,
+    /// Static keys transmitted during the handshake; mutual authentication.
+    ///
+    /// ```text
+    /// pqXX:
+    ///   -> e
+    ///   <- ekem, s
+    ///   -> skem, s
+    ///   <- skem
+    /// ```
+    PqXX,
+}
+
+#[derive(Clone, Copy)]
+enum Token {
+    E,
+    S,
+    Ekem,
+    Skem,
+}
+
+impl Pattern {
+    fn name(self) -> &'static str {
+        match self {
+            Pattern::PqNK => "pqNK",
+            Pattern::PqXX => "pqXX",
+        }
+    }
+
+    /// Whether the responder's static key is a pre-message (`<- s`).
+    fn responder_static_premessage(self) -> bool {
+        matches!(self, Pattern::PqNK)
+    }
+
+    fn messages(self) -> &'static [&'static [Token]] {
+        use Token::*;
+        match self {
+            Pattern::PqNK => &[&[Skem, E], &[Ekem]],
+            Pattern::PqXX => &[&[E], &[Ekem, S], &[Skem, S], &[Skem]],
+        }
+    }
+
+    fn needs_local_static(self, initiator: bool) -> bool {
+        match self {
+            Pattern::PqNK => !initiator,
+            Pattern::PqXX => true,
+        }
+    }
+}
+
+/// ChaChaPoly cipher state: key plus 64-bit nonce counter.
+pub struct CipherState {
+    k: Option<Zeroizing<[u8; 32]>>,
+    n: u64,
+}
+
+impl CipherState {
+    fn empty() -> Self {
+        Self { k: None, n: 0 }
+    }
+
+    fn with_key(k: &[u8]) -> Self {
+        let mut key = Zeroizing::new([0u8; 32]);
+        key.copy_from_slice(&k[..32]);
+        Self { k: Some(key), n: 0 }
+    }
+
+    fn has_key(&self) -> bool {
+        self.k.is_some()
+    }
+
+    /// `0x00000000 || LE64(n)`
+    fn nonce(&self) -> Result<[u8; 12], OqsError> {
+        if self.n == u64::MAX {
+            return Err(OqsError::Protocol("noise nonce exhausted"));
+        }
+        let mut nonce = [0u8; 12];
+        nonce[4..].copy_from_slice(&self.n.to_le_bytes());
+        Ok(nonce)
+    }
+
+    /// `EncryptWithAd`; the identity while no key is set.
+    pub fn encrypt_with_ad(&mut self, ad: &[u8], pt: &[u8]) -> Result<Vec<u8>, OqsError> {
+        let Some(k) = &self.k else {
+            return Ok(pt.to_vec());
+        };
+        let ct = ChaCha20Poly1305::new(k.as_ref().into())
+            .encrypt(&self.nonce()?.into(), Payload { msg: pt, aad: ad })
+            .map_err(|_| OqsError::Internal("aead seal"))?;
+        self.n += 1;
+        Ok(ct)
+    }
+
+    /// `DecryptWithAd`; `VerifyFail` on authentication failure (nonce not consumed).
+    pub fn decrypt_with_ad(&mut self, ad: &[u8], ct: &[u8]) -> Result<Vec<u8>, OqsError> {
+        let Some(k) = &self.k else {
+            return Ok(ct.to_vec());
+        };
+        let pt = ChaCha20Poly1305::new(k.as_ref().into())
+            .decrypt(&self.nonce()?.into(), Payload { msg: ct, aad: ad })
+            .map_err(|_| OqsError::VerifyFail)?;
+        self.n += 1;
+        Ok(pt)
+    }
+}
+
+/// `ck`, `h` and the handshake cipher state.
+struct SymmetricState<H: NoiseHash> {
+    ck: Zeroizing<Vec<u8>>,
+    h: Vec<u8>,
+    cs: CipherState,
+    _hash: PhantomData<H>,
+}
+
+impl<H: NoiseHash> SymmetricState<H> {
+    fn new(protocol_name: &[u8]) -> Self {
+        let hashlen = <H as Digest>::output_size();
+        let h = if protocol_name.len() <= hashlen {
+            let mut h = protocol_name.to_vec();
+            h.resize(hashlen, 0);
+            h
+        } else {
+            H::digest(protocol_name).to_vec()
+        };
+        Self {
+            ck: Zeroizing::new(h.clone()),
+            h,
+            cs: CipherState::empty(),
+            _hash: PhantomData,
+        }
+    }
+
+    /// `HKDF(ck, ikm, 2)`
+    fn hkdf2(&self, ikm: &[u8]) -> Zeroizing<Vec<u8>> {
+        let hashlen = <H as Digest>::output_size();
+        let mut out = Zeroizing::new(vec![0u8; 2 * hashlen]);
+        SimpleHkdf::<H>::new(Some(&self.ck), ikm)
+            .expand(&[], &mut out)
+            .expect("2 * HASHLEN is a valid HKDF length");
+        out
+    }
+
+    fn mix_key(&mut self, ikm: &[u8]) {
+        let hashlen = <H as Digest>::output_size();
+        let out = self.hkdf2(ikm);
+        self.ck = Zeroizing::new(out[..hashlen].to_vec());
+        self.cs = CipherState::with_key(&out[hashlen..]);
+    }
+
+    fn mix_hash(&mut self, data: &[u8]) {
+        let mut d = H::new();
+        Digest::update(&mut d, &self.h);
+        Digest::update(&mut d, data);
+        self.h = d.finalize().to_vec();
+    }
+
+    fn encrypt_and_hash(&mut self, pt: &[u8]) -> Result<Vec<u8>, OqsError> {
+        let ct = self.cs.encrypt_with_ad(&self.h, pt)?;
+        self.mix_hash(&ct);
+        Ok(ct)
+    }
+
+    fn decrypt_and_hash(&mut self, ct: &[u8]) -> Result<Vec<u8>, OqsError> {
+        let pt = self.cs.decrypt_with_ad(&self.h, ct)?;
+        self.mix_hash(ct);
+        Ok(pt)
+    }
+
+    fn split(&self) -> (CipherState, CipherState) {
+        let hashlen = <H as Digest>::output_size();
+        let out = self.hkdf2(&[]);
+        (
+            CipherState::with_key(&out[..hashlen]),
+            CipherState::with_key(&out[hashlen..]),
+        )
+    }
+}
+
+/// Handshake state machine for one party.
+///
+/// Call [`write_message`](Self::write_message) / [`read_message`](Self::read_message)
+/// in pattern order until [`is_finished`](Self::is_finished), then
+/// [`into_transport`](Self::into_transport).
+pub struct HandshakeState<K: Kem, H: NoiseHash> {
+    pattern: Pattern,
+    initiator: bool,
+    sym: SymmetricState<H>,
+    s: Option<(PublicKey, SecretKey)>,
+    e: Option<(PublicKey, SecretKey)>,
+    rs: Option<PublicKey>,
+    re: Option<PublicKey>,
+    msg: usize,
+    _kem: PhantomData<K>,
+}
+
+impl<K: Kem, H: NoiseHash> HandshakeState<K, H> {
+    /// Initiator side. `s` is the local static keypair and `rs` the remote
+    /// static public key, each required only where the pattern needs it.
+    pub fn initiator(
+        pattern: Pattern,
+        prologue: &[u8],
+        s: Option<(PublicKey, SecretKey)>,
+        rs: Option<PublicKey>,
+    ) -> Result<Self, OqsError> {
+        Self::new(pattern, true, prologue, s, rs)
+    }
+
+    /// Responder side; see [`initiator`](Self::initiator).
+    pub fn responder(
+        pattern: Pattern,
+        prologue: &[u8],
+        s: Option<(PublicKey, SecretKey)>,
+        rs: Option<PublicKey>,
+    ) -> Result<Self, OqsError> {
+        Self::new(pattern, false, prologue, s, rs)
+    }
+
+    fn new(
+        pattern: Pattern,
+        initiator: bool,
+        prologue: &[u8],
+        s: Option<(PublicKey, SecretKey)>,
+        rs: Option<PublicKey>,
+    ) -> Result<Self, OqsError> {
+        if pattern.needs_local_static(initiator) && s.is_none() {
+            return Err(OqsError::Protocol("noise pattern needs a local static key"));
+        }
+        if let Some((pk, sk)) = &s {
+            if pk.len() != K::PUBLIC_KEY_LEN || sk.len() != K::SECRET_KEY_LEN {
+                return Err(OqsError::InvalidLength);
+            }
+        }
+        let premessage = pattern.responder_static_premessage();
+        if premessage && initiator && rs.is_none() {
+            return Err(OqsError::Protocol(
+                "noise pattern needs the remote static key",
+            ));
+        }
+        if matches!(&rs, Some(rs) if rs.len() != K::PUBLIC_KEY_LEN) {
+            return Err(OqsError::InvalidLength);
+        }
+
+        let name = format!(
+            "Noise_{}_{}_ChaChaPoly_{}",
+            pattern.name(),
+            K::NAME,
+            H::NAME
+        );
+        let mut sym = SymmetricState::<H>::new(name.as_bytes());
+        sym.mix_hash(prologue);
+        if premessage {
+            let responder_static = if initiator {
+                rs.as_ref().map(PublicKey::as_bytes)
+            } else {
+                s.as_ref().map(|(pk, _)| pk.as_bytes())
+            };
+            sym.mix_hash(responder_static.expect("checked above"));
+        }
+
+        Ok(Self {
+            pattern,
+            initiator,
+            sym,
+            s,
+            e: None,
+            rs,
+            re: None,
+            msg: 0,
+            _kem: PhantomData,
+        })
+    }
+
+    /// Whether all handshake messages have been processed.
+    pub fn is_finished(&self) -> bool {
+        self.msg == self.pattern.messages().len()
+    }
+
+    /// Whether the next handshake message is ours to write.
+    pub fn is_my_turn(&self) -> bool {
+        !self.is_finished() && (self.msg & 1 == 0) == self.initiator
+    }
+
+    /// Handshake hash `h`, for channel binding.
+    pub fn handshake_hash(&self) -> &[u8] {
+        &self.sym.h
+    }
+
+    /// The peer's static public key, once known.
+    pub fn remote_static(&self) -> Option<&PublicKey> {
+        self.rs.as_ref()
+    }
+
+    fn sized(&self, len: usize) -> usize {
+        if self.sym.cs.has_key() {
+            len + TAG_LEN
+        } else {
+            len
+        }
+    }
+
+    /// Writes the next handshake message carrying `payload`.
+    pub fn write_message(&mut self, payload: &[u8]) -> Result<Vec<u8>, OqsError> {
+        if !self.is_my_turn() {
+            return Err(OqsError::Protocol("noise: not our turn to write"));
+        }
+        let mut out = Vec::new();
+        for token in self.pattern.messages()[self.msg] {
+            match token {
+                Token::E => {
+                    let (pk, sk) = K::keypair()?;
+                    out.extend_from_slice(pk.as_bytes());
+                    self.sym.mix_hash(pk.as_bytes());
+                    self.e = Some((pk, sk));
+                }
+                Token::S => {
+                    let pk = self.s.as_ref().expect("checked in new").0.clone();
+                    out.extend(self.sym.encrypt_and_hash(pk.as_bytes())?);
+                }
+                Token::Ekem | Token::Skem => {
+                    let remote = match token {
+                        Token::Ekem => self.re.as_ref(),
+                        _ => self.rs.as_ref(),
+                    }
+                    .ok_or(OqsError::Protocol("noise: remote key not yet known"))?;
+                    let (ct, ss) = K::encapsulate(remote)?;
+                    out.extend(self.sym.encrypt_and_hash(ct.as_bytes())?);
+                    self.sym.mix_key(ss.as_bytes());
+                }
+            }
+        }
+        out.extend(self.sym.encrypt_and_hash(payload)?);
+        self.msg += 1;
+        Ok(out)
+    }
+
+    /// Reads the next handshake message and returns its payload.
+    ///
+    /// On error the handshake must be abandoned.
+    pub fn read_message(&mut self, message: &[u8]) -> Result<Vec<u8>, OqsError> {
+        if self.is_finished() || self.is_my_turn() {
+            return Err(OqsError::Protocol("noise: not our turn to read"));
+        }
+        let mut rest = message;
+        let mut take = |n: usize| -> Result<&[u8], OqsError> {
+            if rest.len() < n {
+                return Err(OqsError::InvalidLength);
+            }
+            let (head, tail) = rest.split_at(n);
+            rest = tail;
+            Ok(head)
+        };
+        for token in self.pattern.messages()[self.msg] {
+            match token {
+                Token::E => {
+                    let pk = take(K::PUBLIC_KEY_LEN)?;
+                    self.sym.mix_hash(pk);
+                    self.re = Some(PublicKey(pk.into()));
+                }
+                Token::S => {
+                    let ct = take(self.sized(K::PUBLIC_KEY_LEN))?;
+                    let pk = self.sym.decrypt_and_hash(ct)?;
+                    self.rs = Some(PublicKey(pk.into()));
+                }
+                Token::Ekem | Token::Skem => {
+                    let sk = match token {
+                        Token::Ekem => self.e.as_ref(),
+                        _ => self.s.as_ref(),
+                    }
+                    .map(|(_, sk)| sk)
+                    .ok_or(OqsError::Protocol("noise: local key missing"))?;
+                    let ct = take(self.sized(K::CIPHERTEXT_LEN))?;
+                    let ct = self.sym.decrypt_and_hash(ct)?;
+                    let ss = K::decapsulate(&Ciphertext(ct), sk)?;
+                    self.sym.mix_key(ss.as_bytes());
+                }
+            }
+        }
+        let payload = self.sym.decrypt_and_hash(rest)?;
+        self.msg += 1;
+        Ok(payload)
+    }
+
+    /// Finishes the handshake: `(send, receive)` transport cipher states.
+    pub fn into_transport(self) -> Result<(CipherState, CipherState), OqsError> {
+        if !self.is_finished() {
+            return Err(OqsError::Protocol("noise handshake not finished"));
+        }
+        let (c1, c2) = self.sym.split();
+        Ok(if self.initiator { (c1, c2) } else { (c2, c1) })
+    }
+}
@@ -0,0 +1,203 @@
+// Copyright (c) 2025 Orlando Trajano
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Caller-supplied randomness.
+//!
+//! [`with_rng`] makes every oqs-safe operation run inside its closure, on the
+//! current thread, draw its randomness from the given [`CryptoRngCore`]:
+//! liboqs key generation, encapsulation and signing (through
+//! `OQS_randombytes_custom_algorithm`), the mock backend, and the classical
+//! halves of the hybrids. Outside `with_rng` the operating system RNG is used.
+//!
+//! ```ignore
+//! let mut rng = rand_chacha::ChaCha20Rng::from_seed([7; 32]);
+//! let (pk, sk) = Kyber768::keypair_with_rng(&mut rng)?; // reproducible
+//! let (pk, sk) = oqs_safe::rng::with_rng(&mut hsm_drbg, || XWing::keypair())?;
+//! ```
+//!
+//! Deterministic output for a given seed is only guaranteed for the same
+//! crate version and backend.
+//!
+//! # Threads
+//!
+//! The RNG lives in a thread-local slot for the duration of one `with_rng`
+//! call; liboqs sees a single, process-wide trampoline (installed by the
+//! first liboqs operation unless [`set_random_source`] picked OpenSSL) that
+//! reads the calling thread's slot. Concurrent callers therefore never share
+//! an RNG, and threads without one fall back to the OS.
+//!
+//! The RNG must be `Send` so it can follow the work onto the helper thread of
+//! [`kem::keypair_on_large_stack`](crate::kem::keypair_on_large_stack), where
+//! the caller blocks until the helper finishes.
+//!
+//! # Panics
+//!
+//! A panicking RNG unwinds out of `with_rng` on the mock backend. Inside a
+//! liboqs call it aborts the process instead: liboqs cannot report an RNG
+//! failure, and unwinding through C is undefined behaviour.
+
+use crate::OqsError;
+use core::cell::Cell;
+use core::ptr::NonNull;
+use rand_core::{CryptoRngCore, OsRng, RngCore};
+use std::sync::{Mutex, PoisonError};
+
+/// Entropy source liboqs uses when no caller RNG is in scope.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RandomSource {
+    /// The operating system RNG (`getrandom`, `arc4random`, ...). Default.
+    System,
+    /// OpenSSL's `RAND_bytes`; needs a liboqs built with OpenSSL. While
+    /// selected, liboqs operations inside [`with_rng`] fail with
+    /// [`OqsError::Config`] instead of ignoring the caller's RNG.
+    OpenSsl,
+}
+
+/// `None` until pinned by [`set_random_source`] or the first liboqs call.
+static SOURCE: Mutex<Option<RandomSource>> = Mutex::new(None);
+
+/// Pins the process-wide [`RandomSource`].
+///
+/// Call it at startup: the source is fixed by the first liboqs operation that
+/// draws randomness, after which only the current source is accepted
+/// ([`OqsError::Config`] otherwise). `OpenSsl` fails with
+/// [`OqsError::Backend`] if liboqs lacks OpenSSL support, and with
+/// [`OqsError::NotImplemented`] on the mock backend, which always uses the OS.
+pub fn set_random_source(source: RandomSource) -> Result<(), OqsError> {
+    let mut current = SOURCE.lock().unwrap_or_else(PoisonError::into_inner);
+    match *current {
+        Some(s) if s == source => Ok(()),
+        Some(_) => Err(OqsError::Config(
+            "random source is fixed after the first liboqs operation",
+        )),
+        None => {
+            #[cfg(feature = "liboqs")]
+            crate::ffi::apply_random_source(source)?;
+            #[cfg(not(feature = "liboqs"))]
+            if source == RandomSource::OpenSsl {
+                return Err(OqsError::NotImplemented);
+            }
+            *current = Some(source);
+            Ok(())
+        }
+    }
+}
+
+/// The [`RandomSource`] in effect (`System` until another one is pinned).
+pub fn random_source() -> RandomSource {
+    SOURCE
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .unwrap_or(RandomSource::System)
+}
+
+/// Fixes the source on first use, installing the trampoline by default.
+#[cfg(feature = "liboqs")]
+pub(crate) fn liboqs_source() -> RandomSource {
+    let mut current = SOURCE.lock().unwrap_or_else(PoisonError::into_inner);
+    *current.get_or_insert_with(|| {
+        crate::ffi::apply_random_source(RandomSource::System)
+            .expect("installing a custom RNG cannot fail");
+        RandomSource::System
+    })
+}
+
+/// Whether [`with_rng`] is active on this thread.
+#[cfg(feature = "liboqs")]
+pub(crate) fn is_scoped() -> bool {
+    SLOT.with(|s| s.get().is_some())
+}
+
+type RngPtr = NonNull<dyn CryptoRngCore + Send>;
+
+thread_local! {
+    static SLOT: Cell<Option<RngPtr>> = Cell::new(None);
+}
+
+/// Puts `prev` back into this thread's slot when dropped.
+pub(crate) struct Restore(Option<RngPtr>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        SLOT.with(|s| s.set(self.0));
+    }
+}
+
+/// Runs `f` with `rng` as the randomness source for oqs-safe operations on
+/// this thread.
+pub fn with_rng<T>(rng: &mut (dyn CryptoRngCore + Send), f: impl FnOnce() -> T) -> T {
+    let ptr: NonNull<dyn CryptoRngCore + Send + '_> = NonNull::from(rng);
+    // SAFETY: only the lifetime is erased. The pointer is removed from the
+    // slot by `_restore` before this function returns or unwinds, i.e. while
+    // `rng` is still mutably borrowed by us.
+    let ptr: RngPtr = unsafe { core::mem::transmute(ptr) };
+    let _restore = Restore(SLOT.with(|s| s.replace(Some(ptr))));
+    f()
+}
+
+/// Fills `buf` from the RNG installed by [`with_rng`], or from the OS.
+pub(crate) fn fill_bytes(buf: &mut [u8]) {
+    match SLOT.with(|s| s.take()) {
+        Some(mut ptr) => {
+            // The slot stays empty while the caller's RNG runs, so a nested
+            // oqs-safe call from inside it cannot alias the `&mut`.
+            let _restore = Restore(Some(ptr));
+            // SAFETY: `ptr` came from a live `&mut` in `with_rng` (see there)
+            // and has just been taken out of the slot, so this is the only
+            // reference to it.
+            unsafe { ptr.as_mut() }.fill_bytes(buf);
+        }
+        None => OsRng.fill_bytes(buf),
+    }
+}
+
+/// This thread's scoped RNG, lent to a scoped helper thread.
+pub(crate) struct Loan(Option<RngPtr>);
+
+// SAFETY: the pointee is `Send`, and `lend` empties the lending thread's slot
+// until the loan is returned, so only one thread can reach it at a time.
+unsafe impl Send for Loan {}
+
+/// Moves this thread's scoped RNG (if any) into a [`Loan`]. The [`Restore`]
+/// guard puts it back and must only be dropped once the borrowing thread has
+/// been joined, e.g. by declaring it before `std::thread::scope`.
+pub(crate) fn lend() -> (Loan, Restore) {
+    let ptr = SLOT.with(|s| s.take());
+    (Loan(ptr), Restore(ptr))
+}
+
+impl Loan {
+    /// Runs `f` with the borrowed RNG installed on the current thread.
+    pub(crate) fn run<T>(self, f: impl FnOnce() -> T) -> T {
+        let _restore = Restore(SLOT.with(|s| s.replace(self.0)));
+        f()
+    }
+}
+
+/// `RngCore` view of [`fill_bytes`], for APIs that take an RNG argument.
+#[cfg(any(feature = "ecdh_p256", feature = "composite"))]
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct CurrentRng;
+
+#[cfg(any(feature = "ecdh_p256", feature = "composite"))]
+impl RngCore for CurrentRng {
+    fn next_u32(&mut self) -> u32 {
+        rand_core::impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        rand_core::impls::next_u64_via_fill(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        fill_bytes(dest);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[cfg(any(feature = "ecdh_p256", feature = "composite"))]
+impl rand_core::CryptoRng for CurrentRng {}
@@ -0,0 +1,242 @@
+// Copyright (c) 2025 Orlando Trajano
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! [rustls] `CryptoProvider` pieces for post-quantum TLS 1.3.
+//!
+//! | item                   | rustls trait                     | codepoint |
+//! |------------------------|----------------------------------|-----------|
+//! | [`X25519_MLKEM768`]    | `SupportedKxGroup`               | `0x11EC`  |
+//! | [`SECP256R1_MLKEM768`] | `SupportedKxGroup`               | `0x11EB`  |
+//! | [`MLKEM1024`]          | `SupportedKxGroup`               | `0x0202`  |
+//! | [`MlDsa44SigningKey`]  | `SigningKey`                     | `0x0904`  |
+//! | [`ML_DSA_44`]          | `SignatureVerificationAlgorithm` | `0x0904`  |
+//!
+//! Key exchange is delegated to [`crate::tls`]; ML-DSA-44 is pure FIPS 204
+//! signing with an empty context, as TLS 1.3 requires. Cipher suites,
+//! randomness and classical algorithms still come from a base provider:
+//!
+//! ```ignore
+//! let provider = oqs_safe::rustls::provider(rustls::crypto::ring::default_provider());
+//! let config = rustls::ClientConfig::builder_with_provider(provider.into())
+//!     .with_protocol_versions(&[&rustls::version::TLS13])?;
+//! ```
+//!
+//! All groups are TLS 1.3 only.
+
+use crate::sig::{Dilithium2, PublicKey, SecretKey, Signature, SignatureScheme as _};
+use crate::tls::{self, ClientKeyShareSecret, NamedGroup};
+use crate::OqsError;
+use ::rustls::crypto::{
+    ActiveKeyExchange, CompletedKeyExchange, CryptoProvider, SharedSecret, SupportedKxGroup,
+    WebPkiSupportedAlgorithms,
+};
+use ::rustls::pki_types::{
+    alg_id, AlgorithmIdentifier, InvalidSignature, SignatureVerificationAlgorithm,
+    SubjectPublicKeyInfoDer,
+};
+use ::rustls::sign::{public_key_to_spki, Signer, SigningKey};
+use ::rustls::{Error, PeerMisbehaved, ProtocolVersion, SignatureAlgorithm};
+use std::fmt;
+use std::sync::Arc;
+
+/// `X25519MLKEM768` hybrid key exchange.
+pub static X25519_MLKEM768: &dyn SupportedKxGroup = &KxGroup(NamedGroup::X25519MlKem768);
+
+/// `SecP256r1MLKEM768` hybrid key exchange.
+pub static SECP256R1_MLKEM768: &dyn SupportedKxGroup = &KxGroup(NamedGroup::SecP256r1MlKem768);
+
+/// Pure `MLKEM1024` key exchange.
+pub static MLKEM1024: &dyn SupportedKxGroup = &KxGroup(NamedGroup::MlKem1024);
+
+/// All groups above, in preference order.
+pub static ALL_KX_GROUPS: &[&dyn SupportedKxGroup] =
+    &[X25519_MLKEM768, SECP256R1_MLKEM768, MLKEM1024];
+
+/// ML-DSA-44 verification, for both `CertificateVerify` and certificate chains.
+pub static ML_DSA_44: &dyn SignatureVerificationAlgorithm = &MlDsa44Verify;
+
+/// Signature verification algorithms contributed by this module.
+pub static SUPPORTED_SIG_ALGS: WebPkiSupportedAlgorithms = WebPkiSupportedAlgorithms {
+    all: &[ML_DSA_44],
+    mapping: &[(::rustls::SignatureScheme::ML_DSA_44, &[ML_DSA_44])],
+};
+
+/// Extends `base` with [`ALL_KX_GROUPS`] (preferred over the base groups) and
+/// [`SUPPORTED_SIG_ALGS`].
+///
+/// The merged signature tables must be `'static`, so each call leaks them;
+/// build the provider once and share it.
+pub fn provider(base: CryptoProvider) -> CryptoProvider {
+    let mut kx_groups = ALL_KX_GROUPS.to_vec();
+    kx_groups.extend(base.kx_groups);
+
+    let algs = base.signature_verification_algorithms;
+    let all = [SUPPORTED_SIG_ALGS.all, algs.all].concat();
+    let mapping = [SUPPORTED_SIG_ALGS.mapping, algs.mapping].concat();
+
+    CryptoProvider {
+        kx_groups,
+        signature_verification_algorithms: WebPkiSupportedAlgorithms {
+            all: Box::leak(all.into_boxed_slice()),
+            mapping: Box::leak(mapping.into_boxed_slice()),
+        },
+        ..base
+    }
+}
+
+fn kx_error(e: OqsError) -> Error {
+    match e {
+        OqsError::InvalidLength | OqsError::InvalidEncoding(_) => {
+            PeerMisbehaved::InvalidKeyShare.into()
+        }
+        e => Error::General(e.to_string()),
+    }
+}
+
+#[derive(Debug)]
+struct KxGroup(NamedGroup);
+
+impl SupportedKxGroup for KxGroup {
+    fn start(&self) -> Result<Box<dyn ActiveKeyExchange>, Error> {
+        let (pub_key, secret) = tls::client_key_share(self.0).map_err(kx_error)?;
+        Ok(Box::new(ActiveKx { pub_key, secret }))
+    }
+
+    fn start_and_complete(&self, peer_pub_key: &[u8]) -> Result<CompletedKeyExchange, Error> {
+        let (pub_key, ss) = tls::server_key_share(self.0, peer_pub_key).map_err(kx_error)?;
+        Ok(CompletedKeyExchange {
+            group: self.name(),
+            pub_key,
+            secret: SharedSecret::from(ss.as_bytes()),
+        })
+    }
+
+    fn name(&self) -> ::rustls::NamedGroup {
+        ::rustls::NamedGroup::from(self.0.codepoint())
+    }
+
+    fn usable_for_version(&self, version: ProtocolVersion) -> bool {
+        version == ProtocolVersion::TLSv1_3
+    }
+}
+
+struct ActiveKx {
+    pub_key: Vec<u8>,
+    secret: ClientKeyShareSecret,
+}
+
+impl ActiveKeyExchange for ActiveKx {
+    fn complete(self: Box<Self>, peer_pub_key: &[u8]) -> Result<SharedSecret, Error> {
+        let ss = tls::client_shared_secret(self.secret, peer_pub_key).map_err(kx_error)?;
+        Ok(SharedSecret::from(ss.as_bytes()))
+    }
+
+    fn pub_key(&self) -> &[u8] {
+        &self.pub_key
+    }
+
+    fn group(&self) -> ::rustls::NamedGroup {
+        ::rustls::NamedGroup::from(self.secret.group().codepoint())
+    }
+}
+
+/// An ML-DSA-44 key pair usable as a rustls [`SigningKey`], e.g. inside a
+/// `rustls::sign::CertifiedKey`.
+#[derive(Clone)]
+pub struct MlDsa44SigningKey {
+    public: PublicKey,
+    secret: Arc<SecretKey>,
+}
+
+impl MlDsa44SigningKey {
+    /// Wraps a `Dilithium2` (ML-DSA-44) key pair.
+    pub fn new(public: PublicKey, secret: SecretKey) -> Result<Self, OqsError> {
+        if public.len() != Dilithium2::PUBLIC_KEY_LEN || secret.len() != Dilithium2::SECRET_KEY_LEN
+        {
+            return Err(OqsError::InvalidLength);
+        }
+        Ok(Self {
+            public,
+            secret: Arc::new(secret),
+        })
+    }
+
+    /// The DER `SubjectPublicKeyInfo` for certificates.
+    pub fn spki(&self) -> SubjectPublicKeyInfoDer<'static> {
+        public_key_to_spki(&alg_id::ML_DSA_44, self.public.as_bytes())
+    }
+}
+
+impl fmt::Debug for MlDsa44SigningKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MlDsa44SigningKey").finish_non_exhaustive()
+    }
+}
+
+impl SigningKey for MlDsa44SigningKey {
+    fn choose_scheme(&self, offered: &[::rustls::SignatureScheme]) -> Option<Box<dyn Signer>> {
+        if !offered.contains(&::rustls::SignatureScheme::ML_DSA_44) {
+            return None;
+        }
+        Some(Box::new(MlDsa44Signer(self.secret.clone())))
+    }
+
+    fn public_key(&self) -> Option<SubjectPublicKeyInfoDer<'_>> {
+        Some(self.spki())
+    }
+
+    fn algorithm(&self) -> SignatureAlgorithm {
+        // rustls has no `SignatureAlgorithm` variant for ML-DSA yet.
+        SignatureAlgorithm::Unknown(0)
+    }
+}
+
+struct MlDsa44Signer(Arc<SecretKey>);
+
+impl fmt::Debug for MlDsa44Signer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MlDsa44Signer").finish_non_exhaustive()
+    }
+}
+
+impl Signer for MlDsa44Signer {
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>, Error> {
+        Dilithium2::sign(&self.0, message)
+            .map(|sig| sig.0)
+            .map_err(|e| Error::General(e.to_string()))
+    }
+
+    fn scheme(&self) -> ::rustls::SignatureScheme {
+        ::rustls::SignatureScheme::ML_DSA_44
+    }
+}
+
+#[derive(Debug)]
+struct MlDsa44Verify;
+
+impl SignatureVerificationAlgorithm for MlDsa44Verify {
+    fn verify_signature(
+        &self,
+        public_key: &[u8],
+        message: &[u8],
+        signature: &[u8],
+    ) -> Result<(), InvalidSignature> {
+        if public_key.len() != Dilithium2::PUBLIC_KEY_LEN {
+            return Err(InvalidSignature);
+        }
+        Dilithium2::verify(
+            &PublicKey(public_key.to_vec()),
+            message,
+            &Signature(signature.to_vec()),
+        )
+        .map_err(|_| InvalidSignature)
+    }
+
+    fn public_key_alg_id(&self) -> AlgorithmIdentifier {
+        alg_id::ML_DSA_44
+    }
+
+    fn signature_alg_id(&self) -> AlgorithmIdentifier {
+        alg_id::ML_DSA_44
+    }
+}
@@ -0,0 +1,242 @@
+// Copyright (c) 2025 Orlando Trajano
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Backing storage for secret keys and shared secrets.
+//!
+//! | build                    | storage                                          |
+//! |--------------------------|--------------------------------------------------|
+//! | default                  | heap `Vec<u8>`, zeroized on drop                 |
+//! | `secure-memory`, unix    | own `mmap` region between two guard pages,       |
+//! |                          | `mlock`ed and `MADV_DONTDUMP` (Linux); zeroized  |
+//! |                          | and unmapped on drop                             |
+//!
+//! With `secure-memory` the bytes end right at the trailing guard page, so an
+//! overrun faults instead of reading neighbouring heap memory (the layout of
+//! libsodium's `sodium_malloc`). Locking and `MADV_DONTDUMP` are best effort:
+//! when `RLIMIT_MEMLOCK` is exhausted the buffer is still guarded and
+//! zeroized, just swappable. Every buffer costs at least three pages.
+//!
+//! liboqs writes secret outputs straight into a `SecretBuf`, and conversions
+//! from a `Vec<u8>` cleanse the source, mirroring `OQS_MEM_secure_free`.
+
+use core::fmt;
+use core::ops::{Deref, DerefMut};
+
+pub(crate) use imp::SecretBuf;
+
+/// Whether secret buffers are guard-paged and locked in this build.
+pub(crate) const SECURE: bool = cfg!(all(feature = "secure-memory", unix));
+
+#[cfg(not(all(feature = "secure-memory", unix)))]
+mod imp {
+    use zeroize::Zeroize;
+
+    #[derive(Clone)]
+    pub(crate) struct SecretBuf(Vec<u8>);
+
+    impl SecretBuf {
+        pub(crate) fn zeroed(len: usize) -> Self {
+            SecretBuf(vec![0u8; len])
+        }
+
+        pub(crate) fn as_slice(&self) -> &[u8] {
+            &self.0
+        }
+
+        pub(crate) fn as_mut_slice(&mut self) -> &mut [u8] {
+            &mut self.0
+        }
+    }
+
+    impl From<Vec<u8>> for SecretBuf {
+        fn from(bytes: Vec<u8>) -> Self {
+            SecretBuf(bytes)
+        }
+    }
+
+    impl Zeroize for SecretBuf {
+        fn zeroize(&mut self) {
+            self.0.zeroize();
+        }
+    }
+
+    impl Drop for SecretBuf {
+        fn drop(&mut self) {
+            self.0.zeroize();
+        }
+    }
+}
+
+#[cfg(all(feature = "secure-memory", unix))]
+mod imp {
+    use core::ptr::{self, NonNull};
+    use std::alloc::{handle_alloc_error, Layout};
+    use std::sync::OnceLock;
+    use zeroize::Zeroize;
+
+    /// `len` bytes at the end of an `mmap`ed, guard-paged region.
+    pub(crate) struct SecretBuf {
+        ptr: NonNull<u8>,
+        len: usize,
+        /// Start and size of the read-write pages (`None` when empty).
+        data: Option<(NonNull<u8>, usize)>,
+    }
+
+    // SAFETY: the region is owned exclusively by this value, like a `Vec`.
+    unsafe impl Send for SecretBuf {}
+    unsafe impl Sync for SecretBuf {}
+
+    fn page_size() -> usize {
+        static PAGE: OnceLock<usize> = OnceLock::new();
+        // SAFETY: `sysconf` has no memory-safety preconditions.
+        *PAGE.get_or_init(|| match unsafe { libc::sysconf(libc::_SC_PAGESIZE) } {
+            n if n > 0 => n as usize,
+            _ => 4096,
+        })
+    }
+
+    impl SecretBuf {
+        pub(crate) fn zeroed(len: usize) -> Self {
+            if len == 0 {
+                return SecretBuf {
+                    ptr: NonNull::dangling(),
+                    len: 0,
+                    data: None,
+                };
+            }
+            let page = page_size();
+            let data_len = len
+                .checked_add(page - 1)
+                .map(|n| n / page * page)
+                .expect("secret buffer size overflow");
+            let total = data_len + 2 * page;
+            let oom = || handle_alloc_error(Layout::from_size_align(total, page).unwrap());
+
+            // SAFETY: a fresh anonymous mapping; every pointer below stays
+            // inside it, and it is unmapped again on each failure path.
+            unsafe {
+                let base = libc::mmap(
+                    ptr::null_mut(),
+                    total,
+                    libc::PROT_NONE,
+                    libc::MAP_PRIVATE | libc::MAP_ANON,
+                    -1,
+                    0,
+                );
+                if base == libc::MAP_FAILED {
+                    oom();
+                }
+                let data = base.cast::<u8>().add(page);
+                if libc::mprotect(data.cast(), data_len, libc::PROT_READ | libc::PROT_WRITE) != 0 {
+                    libc::munmap(base, total);
+                    oom();
+                }
+                // Best effort; see the module docs.
+                libc::mlock(data.cast(), data_len);
+                #[cfg(any(target_os = "linux", target_os = "android"))]
+                libc::madvise(data.cast(), data_len, libc::MADV_DONTDUMP);
+
+                SecretBuf {
+                    ptr: NonNull::new_unchecked(data.add(data_len - len)),
+                    len,
+                    data: Some((NonNull::new_unchecked(data), data_len)),
+                }
+            }
+        }
+
+        pub(crate) fn as_slice(&self) -> &[u8] {
+            // SAFETY: `ptr..ptr + len` is readable and owned by `self`.
+            unsafe { core::slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+        }
+
+        pub(crate) fn as_mut_slice(&mut self) -> &mut [u8] {
+            // SAFETY: as above, and `&mut self` makes the access exclusive.
+            unsafe { core::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+        }
+    }
+
+    impl From<Vec<u8>> for SecretBuf {
+        fn from(mut bytes: Vec<u8>) -> Self {
+            let mut buf = SecretBuf::zeroed(bytes.len());
+            buf.as_mut_slice().copy_from_slice(&bytes);
+            bytes.zeroize();
+            buf
+        }
+    }
+
+    impl Clone for SecretBuf {
+        fn clone(&self) -> Self {
+            let mut buf = SecretBuf::zeroed(self.len);
+            buf.as_mut_slice().copy_from_slice(self.as_slice());
+            buf
+        }
+    }
+
+    impl Zeroize for SecretBuf {
+        /// Clears the bytes and, like `Vec::zeroize`, empties the buffer.
+        fn zeroize(&mut self) {
+            self.as_mut_slice().zeroize();
+            self.len = 0;
+        }
+    }
+
+    impl Drop for SecretBuf {
+        fn drop(&mut self) {
+            let Some((data, data_len)) = self.data else {
+                return;
+            };
+            let page = page_size();
+            // SAFETY: `data..data + data_len` is the read-write part of the
+            // mapping created in `zeroed`, which starts one page earlier.
+            unsafe {
+                core::slice::from_raw_parts_mut(data.as_ptr(), data_len).zeroize();
+                libc::munlock(data.as_ptr().cast(), data_len);
+                libc::munmap(data.as_ptr().sub(page).cast(), data_len + 2 * page);
+            }
+        }
+    }
+}
+
+impl Deref for SecretBuf {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl DerefMut for SecretBuf {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        self.as_mut_slice()
+    }
+}
+
+impl From<&[u8]> for SecretBuf {
+    fn from(bytes: &[u8]) -> Self {
+        let mut buf = SecretBuf::zeroed(bytes.len());
+        buf.copy_from_slice(bytes);
+        buf
+    }
+}
+
+/// Redacted: only the length is shown, so secrets stay out of logs and
+/// panic messages.
+impl fmt::Debug for SecretBuf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SecretBuf(<{} bytes>)", self.len())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for SecretBuf {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_slice().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SecretBuf {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::<u8>::deserialize(deserializer).map(SecretBuf::from)
+    }
+}
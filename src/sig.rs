@@ -1,24 +1,25 @@
 //! Signature API with safe accessors and feature-gated RNG for the mock backend.
 
+use crate::secret::SecretBuf;
 use crate::OqsError;
+use subtle::{Choice, ConstantTimeEq};
 use zeroize::Zeroize;
 
-#[cfg(not(feature = "liboqs"))]
-use rand_core::{OsRng, RngCore};
+use rand_core::CryptoRngCore;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PublicKey(pub(crate) Vec<u8>);
 
 #[derive(Clone, Debug, Zeroize)]
 #[zeroize(drop)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct SecretKey(pub(crate) Vec<u8>);
+pub struct SecretKey(pub(crate) SecretBuf);
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Signature(pub(crate) Vec<u8>);
 
@@ -66,60 +67,715 @@ impl Signature {
     }
 }
 
+// Constant time; see `kem::SecretKey`.
+impl ConstantTimeEq for SecretKey {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.as_bytes().ct_eq(other.as_bytes())
+    }
+}
+impl PartialEq for SecretKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+impl Eq for SecretKey {}
+
 pub trait SignatureScheme {
+    /// Primary liboqs method name (e.g. `"ML-DSA-44"`).
+    const NAME: &'static str;
+    /// Public key length in bytes.
+    const PUBLIC_KEY_LEN: usize;
+    /// Secret key length in bytes.
+    const SECRET_KEY_LEN: usize;
+    /// Maximum signature length in bytes.
+    const SIGNATURE_LEN: usize;
+    /// `false` for schemes that are not (yet) a NIST standard, e.g. the
+    /// additional-signature onramp candidates in `sig::experimental`.
+    const STANDARDIZED: bool;
+
     fn keypair() -> Result<(PublicKey, SecretKey), OqsError>;
     fn sign(sk: &SecretKey, msg: &[u8]) -> Result<Signature, OqsError>;
     fn verify(pk: &PublicKey, msg: &[u8], sig: &Signature) -> Result<(), OqsError>;
+
+    /// [`keypair`](Self::keypair) drawing its randomness from `rng`
+    /// (see [`crate::rng`]).
+    fn keypair_with_rng(
+        rng: &mut (impl CryptoRngCore + Send),
+    ) -> Result<(PublicKey, SecretKey), OqsError> {
+        crate::rng::with_rng(rng, Self::keypair)
+    }
+
+    /// [`sign`](Self::sign) drawing any signing randomness from `rng`
+    /// (see [`crate::rng`]).
+    fn sign_with_rng(
+        sk: &SecretKey,
+        msg: &[u8],
+        rng: &mut (impl CryptoRngCore + Send),
+    ) -> Result<Signature, OqsError> {
+        crate::rng::with_rng(rng, || Self::sign(sk, msg))
+    }
 }
 
-pub struct Dilithium2;
+/// Object-safe, instance-based view of a [`SignatureScheme`].
+///
+/// Every `SignatureScheme` implements it, so schemes can be chosen at
+/// runtime or injected as `&dyn DynSignatureScheme`, and test doubles can
+/// implement it directly. Method names differ from [`SignatureScheme`]'s so
+/// both traits can be in scope at once.
+pub trait DynSignatureScheme: Send + Sync {
+    /// See [`SignatureScheme::NAME`].
+    fn name(&self) -> &'static str;
+    /// See [`SignatureScheme::PUBLIC_KEY_LEN`].
+    fn public_key_len(&self) -> usize;
+    /// See [`SignatureScheme::SECRET_KEY_LEN`].
+    fn secret_key_len(&self) -> usize;
+    /// See [`SignatureScheme::SIGNATURE_LEN`].
+    fn signature_len(&self) -> usize;
+    /// See [`SignatureScheme::STANDARDIZED`].
+    fn is_standardized(&self) -> bool;
+
+    /// See [`SignatureScheme::keypair`].
+    fn generate_keypair(&self) -> Result<(PublicKey, SecretKey), OqsError>;
+    /// See [`SignatureScheme::sign`].
+    fn sign_message(&self, sk: &SecretKey, msg: &[u8]) -> Result<Signature, OqsError>;
+    /// See [`SignatureScheme::verify`].
+    fn verify_message(&self, pk: &PublicKey, msg: &[u8], sig: &Signature) -> Result<(), OqsError>;
 
-impl SignatureScheme for Dilithium2 {
-    fn keypair() -> Result<(PublicKey, SecretKey), OqsError> {
-        #[cfg(feature = "liboqs")]
-        {
-            let (pk, sk) = crate::ffi::dilithium2_keypair()?;
-            Ok((PublicKey(pk), SecretKey(sk)))
+    /// [`generate_keypair`](Self::generate_keypair) drawing its randomness
+    /// from `rng` (see [`crate::rng`]).
+    fn generate_keypair_with_rng(
+        &self,
+        rng: &mut (dyn CryptoRngCore + Send),
+    ) -> Result<(PublicKey, SecretKey), OqsError> {
+        crate::rng::with_rng(rng, || self.generate_keypair())
+    }
+
+    /// [`sign_message`](Self::sign_message) drawing its randomness from
+    /// `rng` (see [`crate::rng`]).
+    fn sign_message_with_rng(
+        &self,
+        sk: &SecretKey,
+        msg: &[u8],
+        rng: &mut (dyn CryptoRngCore + Send),
+    ) -> Result<Signature, OqsError> {
+        crate::rng::with_rng(rng, || self.sign_message(sk, msg))
+    }
+}
+
+impl<S: SignatureScheme + Send + Sync> DynSignatureScheme for S {
+    fn name(&self) -> &'static str {
+        S::NAME
+    }
+    fn public_key_len(&self) -> usize {
+        S::PUBLIC_KEY_LEN
+    }
+    fn secret_key_len(&self) -> usize {
+        S::SECRET_KEY_LEN
+    }
+    fn signature_len(&self) -> usize {
+        S::SIGNATURE_LEN
+    }
+    fn is_standardized(&self) -> bool {
+        S::STANDARDIZED
+    }
+
+    fn generate_keypair(&self) -> Result<(PublicKey, SecretKey), OqsError> {
+        S::keypair()
+    }
+    fn sign_message(&self, sk: &SecretKey, msg: &[u8]) -> Result<Signature, OqsError> {
+        S::sign(sk, msg)
+    }
+    fn verify_message(&self, pk: &PublicKey, msg: &[u8], sig: &Signature) -> Result<(), OqsError> {
+        S::verify(pk, msg, sig)
+    }
+}
+
+// ---- Mock backend helpers (size-faithful random buffers) ----
+
+#[cfg(not(feature = "liboqs"))]
+fn mock_keypair<S: SignatureScheme>() -> Result<(PublicKey, SecretKey), OqsError> {
+    let mut pk = vec![0u8; S::PUBLIC_KEY_LEN];
+    let mut sk = SecretBuf::zeroed(S::SECRET_KEY_LEN);
+    crate::rng::fill_bytes(&mut pk);
+    crate::rng::fill_bytes(&mut sk);
+    Ok((PublicKey(pk), SecretKey(sk)))
+}
+
+#[cfg(not(feature = "liboqs"))]
+fn mock_sign<S: SignatureScheme>(sk: &SecretKey, msg: &[u8]) -> Result<Signature, OqsError> {
+    // Silence unused warnings on mock path
+    let _ = (sk, msg);
+    let mut sig = vec![0u8; S::SIGNATURE_LEN];
+    crate::rng::fill_bytes(&mut sig);
+    Ok(Signature(sig))
+}
+
+#[cfg(not(feature = "liboqs"))]
+fn mock_verify<S: SignatureScheme>(
+    pk: &PublicKey,
+    msg: &[u8],
+    sig: &Signature,
+) -> Result<(), OqsError> {
+    // Silence unused warnings on mock path
+    let _ = (pk, msg);
+    if sig.len() != S::SIGNATURE_LEN {
+        return Err(OqsError::InvalidLength);
+    }
+    Ok(())
+}
+
+/// Defines zero-sized signature types backed by liboqs method names (tried
+/// in order, to absorb upstream renames) with the mock backend as fallback.
+macro_rules! oqs_sig {
+    ($(
+        $(#[$attr:meta])*
+        $name:ident {
+            names: [$primary:literal $(, $fallback:literal)* $(,)?],
+            public_key: $pk:expr,
+            secret_key: $sk:expr,
+            signature: $sig:expr,
+            standardized: $std:expr $(,)?
         }
-        #[cfg(not(feature = "liboqs"))]
-        {
-            // Mock path: size-faithful random buffers for CI / no-liboqs environments.
-            let mut pk = vec![0u8; 1312];
-            let mut sk = vec![0u8; 2528];
-            OsRng.fill_bytes(&mut pk);
-            OsRng.fill_bytes(&mut sk);
-            Ok((PublicKey(pk), SecretKey(sk)))
+    )*) => {$(
+        $(#[$attr])*
+        pub struct $name;
+
+        impl SignatureScheme for $name {
+            const NAME: &'static str = $primary;
+            const PUBLIC_KEY_LEN: usize = $pk;
+            const SECRET_KEY_LEN: usize = $sk;
+            const SIGNATURE_LEN: usize = $sig;
+            const STANDARDIZED: bool = $std;
+
+            fn keypair() -> Result<(PublicKey, SecretKey), OqsError> {
+                #[cfg(feature = "liboqs")]
+                {
+                    let (pk, sk) = crate::ffi::sig_keypair(&[$primary $(, $fallback)*])?;
+                    Ok((PublicKey(pk), SecretKey(sk)))
+                }
+                #[cfg(not(feature = "liboqs"))]
+                {
+                    mock_keypair::<Self>()
+                }
+            }
+
+            fn sign(sk: &SecretKey, msg: &[u8]) -> Result<Signature, OqsError> {
+                #[cfg(feature = "liboqs")]
+                {
+                    crate::ffi::sig_sign(&[$primary $(, $fallback)*], sk.as_bytes(), msg)
+                        .map(Signature)
+                }
+                #[cfg(not(feature = "liboqs"))]
+                {
+                    mock_sign::<Self>(sk, msg)
+                }
+            }
+
+            fn verify(pk: &PublicKey, msg: &[u8], sig: &Signature) -> Result<(), OqsError> {
+                #[cfg(feature = "liboqs")]
+                {
+                    crate::ffi::sig_verify(
+                        &[$primary $(, $fallback)*],
+                        pk.as_bytes(),
+                        msg,
+                        sig.as_bytes(),
+                    )
+                }
+                #[cfg(not(feature = "liboqs"))]
+                {
+                    mock_verify::<Self>(pk, msg, sig)
+                }
+            }
         }
+    )*};
+}
+
+oqs_sig! {
+    /// Dilithium2 / ML-DSA-44 (FIPS 204)
+    Dilithium2 {
+        names: ["ML-DSA-44", "Dilithium2", "ML-DSA-2"],
+        public_key: 1312,
+        secret_key: 2560,
+        signature: 2420,
+        standardized: true,
     }
+}
+
+/// FIPS 204 ML-DSA with a context string. The composite signatures build on
+/// it; under `testing` it is public so ACVP vectors can drive it.
+#[cfg(any(feature = "composite", feature = "testing"))]
+macro_rules! ml_dsa_context {
+    ($vis:vis $($name:ident => $alg:literal),* $(,)?) => {$(
+        impl $name {
+            /// ML-DSA `Sign` with a FIPS 204 context string (at most 255 bytes).
+            $vis fn sign_with_context(
+                sk: &SecretKey,
+                msg: &[u8],
+                ctx: &[u8],
+            ) -> Result<Signature, OqsError> {
+                if ctx.len() > 255 {
+                    return Err(OqsError::InvalidLength);
+                }
+                #[cfg(feature = "liboqs")]
+                {
+                    crate::ffi::sig_sign_with_ctx(&[$alg], sk.as_bytes(), msg, ctx).map(Signature)
+                }
+                #[cfg(not(feature = "liboqs"))]
+                {
+                    mock_sign::<Self>(sk, msg)
+                }
+            }
 
-    fn sign(sk: &SecretKey, msg: &[u8]) -> Result<Signature, OqsError> {
-        #[cfg(feature = "liboqs")]
-        {
-            crate::ffi::dilithium2_sign(sk.as_bytes(), msg).map(Signature)
+            /// ML-DSA `Verify` with a FIPS 204 context string (at most 255 bytes).
+            $vis fn verify_with_context(
+                pk: &PublicKey,
+                msg: &[u8],
+                sig: &Signature,
+                ctx: &[u8],
+            ) -> Result<(), OqsError> {
+                if ctx.len() > 255 {
+                    return Err(OqsError::InvalidLength);
+                }
+                #[cfg(feature = "liboqs")]
+                {
+                    crate::ffi::sig_verify_with_ctx(&[$alg], pk.as_bytes(), msg, sig.as_bytes(), ctx)
+                }
+                #[cfg(not(feature = "liboqs"))]
+                {
+                    mock_verify::<Self>(pk, msg, sig)
+                }
+            }
         }
-        #[cfg(not(feature = "liboqs"))]
-        {
-            // Silence unused warnings on mock path
-            let _ = (sk, msg);
-            let mut sig = vec![0u8; 2420];
-            OsRng.fill_bytes(&mut sig);
-            Ok(Signature(sig))
+    )*};
+}
+
+#[cfg(feature = "testing")]
+ml_dsa_context!(pub Dilithium2 => "ML-DSA-44");
+
+#[cfg(all(feature = "composite", not(feature = "testing")))]
+ml_dsa_context!(pub(crate) Dilithium2 => "ML-DSA-44");
+
+#[cfg(feature = "signature")]
+mod rustcrypto;
+
+#[cfg(feature = "signature")]
+#[cfg_attr(docsrs, doc(cfg(feature = "signature")))]
+pub use rustcrypto::{SigningKey, VerifyingKey};
+
+/// NIST additional-signature onramp candidates (MAYO, UOV, SNOVA, CROSS).
+///
+/// **Experimental:** none of these is a NIST standard, parameters may still
+/// change upstream, and every type reports `STANDARDIZED == false`. Intended
+/// for evaluation and benchmarking, not production.
+#[cfg(feature = "additional-signatures")]
+#[cfg_attr(docsrs, doc(cfg(feature = "additional-signatures")))]
+pub mod experimental {
+    use super::*;
+
+    oqs_sig! {
+        // ---- MAYO (multivariate) ----
+
+        /// MAYO-1 (claimed NIST level 1; not standardized)
+        Mayo1 {
+            names: ["MAYO-1"],
+            public_key: 1420,
+            secret_key: 24,
+            signature: 454,
+            standardized: false,
         }
-    }
+        /// MAYO-2 (claimed NIST level 1; not standardized)
+        Mayo2 {
+            names: ["MAYO-2"],
+            public_key: 4912,
+            secret_key: 24,
+            signature: 186,
+            standardized: false,
+        }
+        /// MAYO-3 (claimed NIST level 3; not standardized)
+        Mayo3 {
+            names: ["MAYO-3"],
+            public_key: 2986,
+            secret_key: 32,
+            signature: 681,
+            standardized: false,
+        }
+        /// MAYO-5 (claimed NIST level 5; not standardized)
+        Mayo5 {
+            names: ["MAYO-5"],
+            public_key: 5554,
+            secret_key: 40,
+            signature: 964,
+            standardized: false,
+        }
+
+        // ---- CROSS (restricted syndrome decoding) ----
 
-    fn verify(pk: &PublicKey, msg: &[u8], sig: &Signature) -> Result<(), OqsError> {
-        #[cfg(feature = "liboqs")]
-        {
-            crate::ffi::dilithium2_verify(pk.as_bytes(), msg, sig.as_bytes())
+        /// cross-rsdp-128-balanced (claimed NIST level 1; not standardized)
+        CrossRsdp128Balanced {
+            names: ["cross-rsdp-128-balanced"],
+            public_key: 77,
+            secret_key: 32,
+            signature: 13_152,
+            standardized: false,
         }
-        #[cfg(not(feature = "liboqs"))]
-        {
-            // Silence unused warnings on mock path
-            let _ = (pk, msg);
-            if sig.len() != 2420 {
-                return Err(OqsError::InvalidLength);
-            }
-            Ok(())
+        /// cross-rsdp-128-fast (claimed NIST level 1; not standardized)
+        CrossRsdp128Fast {
+            names: ["cross-rsdp-128-fast"],
+            public_key: 77,
+            secret_key: 32,
+            signature: 18_432,
+            standardized: false,
+        }
+        /// cross-rsdp-128-small (claimed NIST level 1; not standardized)
+        CrossRsdp128Small {
+            names: ["cross-rsdp-128-small"],
+            public_key: 77,
+            secret_key: 32,
+            signature: 12_432,
+            standardized: false,
+        }
+        /// cross-rsdp-192-balanced (claimed NIST level 3; not standardized)
+        CrossRsdp192Balanced {
+            names: ["cross-rsdp-192-balanced"],
+            public_key: 115,
+            secret_key: 48,
+            signature: 29_853,
+            standardized: false,
+        }
+        /// cross-rsdp-192-fast (claimed NIST level 3; not standardized)
+        CrossRsdp192Fast {
+            names: ["cross-rsdp-192-fast"],
+            public_key: 115,
+            secret_key: 48,
+            signature: 41_406,
+            standardized: false,
+        }
+        /// cross-rsdp-192-small (claimed NIST level 3; not standardized)
+        CrossRsdp192Small {
+            names: ["cross-rsdp-192-small"],
+            public_key: 115,
+            secret_key: 48,
+            signature: 28_391,
+            standardized: false,
+        }
+        /// cross-rsdp-256-balanced (claimed NIST level 5; not standardized)
+        CrossRsdp256Balanced {
+            names: ["cross-rsdp-256-balanced"],
+            public_key: 153,
+            secret_key: 64,
+            signature: 53_527,
+            standardized: false,
+        }
+        /// cross-rsdp-256-fast (claimed NIST level 5; not standardized)
+        CrossRsdp256Fast {
+            names: ["cross-rsdp-256-fast"],
+            public_key: 153,
+            secret_key: 64,
+            signature: 74_590,
+            standardized: false,
+        }
+        /// cross-rsdp-256-small (claimed NIST level 5; not standardized)
+        CrossRsdp256Small {
+            names: ["cross-rsdp-256-small"],
+            public_key: 153,
+            secret_key: 64,
+            signature: 50_818,
+            standardized: false,
+        }
+        /// cross-rsdpg-128-balanced (claimed NIST level 1; not standardized)
+        CrossRsdpg128Balanced {
+            names: ["cross-rsdpg-128-balanced"],
+            public_key: 54,
+            secret_key: 32,
+            signature: 9120,
+            standardized: false,
+        }
+        /// cross-rsdpg-128-fast (claimed NIST level 1; not standardized)
+        CrossRsdpg128Fast {
+            names: ["cross-rsdpg-128-fast"],
+            public_key: 54,
+            secret_key: 32,
+            signature: 11_980,
+            standardized: false,
+        }
+        /// cross-rsdpg-128-small (claimed NIST level 1; not standardized)
+        CrossRsdpg128Small {
+            names: ["cross-rsdpg-128-small"],
+            public_key: 54,
+            secret_key: 32,
+            signature: 8960,
+            standardized: false,
+        }
+        /// cross-rsdpg-192-balanced (claimed NIST level 3; not standardized)
+        CrossRsdpg192Balanced {
+            names: ["cross-rsdpg-192-balanced"],
+            public_key: 83,
+            secret_key: 48,
+            signature: 22_464,
+            standardized: false,
+        }
+        /// cross-rsdpg-192-fast (claimed NIST level 3; not standardized)
+        CrossRsdpg192Fast {
+            names: ["cross-rsdpg-192-fast"],
+            public_key: 83,
+            secret_key: 48,
+            signature: 26_772,
+            standardized: false,
+        }
+        /// cross-rsdpg-192-small (claimed NIST level 3; not standardized)
+        CrossRsdpg192Small {
+            names: ["cross-rsdpg-192-small"],
+            public_key: 83,
+            secret_key: 48,
+            signature: 20_452,
+            standardized: false,
+        }
+        /// cross-rsdpg-256-balanced (claimed NIST level 5; not standardized)
+        CrossRsdpg256Balanced {
+            names: ["cross-rsdpg-256-balanced"],
+            public_key: 106,
+            secret_key: 64,
+            signature: 40_100,
+            standardized: false,
+        }
+        /// cross-rsdpg-256-fast (claimed NIST level 5; not standardized)
+        CrossRsdpg256Fast {
+            names: ["cross-rsdpg-256-fast"],
+            public_key: 106,
+            secret_key: 64,
+            signature: 48_102,
+            standardized: false,
+        }
+        /// cross-rsdpg-256-small (claimed NIST level 5; not standardized)
+        CrossRsdpg256Small {
+            names: ["cross-rsdpg-256-small"],
+            public_key: 106,
+            secret_key: 64,
+            signature: 36_454,
+            standardized: false,
+        }
+
+        // ---- UOV (multivariate, Oil and Vinegar) ----
+
+        /// OV-Is (claimed NIST level 1; not standardized)
+        OvIs {
+            names: ["OV-Is"],
+            public_key: 412_160,
+            secret_key: 348_704,
+            signature: 96,
+            standardized: false,
+        }
+        /// OV-Ip (claimed NIST level 1; not standardized)
+        OvIp {
+            names: ["OV-Ip"],
+            public_key: 278_432,
+            secret_key: 237_896,
+            signature: 128,
+            standardized: false,
+        }
+        /// OV-III (claimed NIST level 3; not standardized)
+        OvIII {
+            names: ["OV-III"],
+            public_key: 1_225_440,
+            secret_key: 1_044_320,
+            signature: 200,
+            standardized: false,
+        }
+        /// OV-V (claimed NIST level 5; not standardized)
+        OvV {
+            names: ["OV-V"],
+            public_key: 2_869_440,
+            secret_key: 2_436_704,
+            signature: 260,
+            standardized: false,
+        }
+        /// OV-Is-pkc (claimed NIST level 1; not standardized)
+        OvIsPkc {
+            names: ["OV-Is-pkc"],
+            public_key: 66_576,
+            secret_key: 348_704,
+            signature: 96,
+            standardized: false,
+        }
+        /// OV-Ip-pkc (claimed NIST level 1; not standardized)
+        OvIpPkc {
+            names: ["OV-Ip-pkc"],
+            public_key: 43_576,
+            secret_key: 237_896,
+            signature: 128,
+            standardized: false,
+        }
+        /// OV-III-pkc (claimed NIST level 3; not standardized)
+        OvIIIPkc {
+            names: ["OV-III-pkc"],
+            public_key: 189_232,
+            secret_key: 1_044_320,
+            signature: 200,
+            standardized: false,
+        }
+        /// OV-V-pkc (claimed NIST level 5; not standardized)
+        OvVPkc {
+            names: ["OV-V-pkc"],
+            public_key: 446_992,
+            secret_key: 2_436_704,
+            signature: 260,
+            standardized: false,
         }
+        /// OV-Is-pkc-skc (claimed NIST level 1; not standardized)
+        OvIsPkcSkc {
+            names: ["OV-Is-pkc-skc"],
+            public_key: 66_576,
+            secret_key: 32,
+            signature: 96,
+            standardized: false,
+        }
+        /// OV-Ip-pkc-skc (claimed NIST level 1; not standardized)
+        OvIpPkcSkc {
+            names: ["OV-Ip-pkc-skc"],
+            public_key: 43_576,
+            secret_key: 32,
+            signature: 128,
+            standardized: false,
+        }
+        /// OV-III-pkc-skc (claimed NIST level 3; not standardized)
+        OvIIIPkcSkc {
+            names: ["OV-III-pkc-skc"],
+            public_key: 189_232,
+            secret_key: 32,
+            signature: 200,
+            standardized: false,
+        }
+        /// OV-V-pkc-skc (claimed NIST level 5; not standardized)
+        OvVPkcSkc {
+            names: ["OV-V-pkc-skc"],
+            public_key: 446_992,
+            secret_key: 32,
+            signature: 260,
+            standardized: false,
+        }
+
+        // ---- SNOVA (multivariate) ----
+
+        /// SNOVA_24_5_4 (claimed NIST level 1; not standardized)
+        Snova24_5_4 {
+            names: ["SNOVA_24_5_4"],
+            public_key: 1016,
+            secret_key: 48,
+            signature: 248,
+            standardized: false,
+        }
+        /// SNOVA_24_5_4_SHAKE (claimed NIST level 1; not standardized)
+        Snova24_5_4Shake {
+            names: ["SNOVA_24_5_4_SHAKE"],
+            public_key: 1016,
+            secret_key: 48,
+            signature: 248,
+            standardized: false,
+        }
+        /// SNOVA_24_5_4_esk (claimed NIST level 1; not standardized)
+        Snova24_5_4Esk {
+            names: ["SNOVA_24_5_4_esk"],
+            public_key: 1016,
+            secret_key: 36_848,
+            signature: 248,
+            standardized: false,
+        }
+        /// SNOVA_24_5_4_SHAKE_esk (claimed NIST level 1; not standardized)
+        Snova24_5_4ShakeEsk {
+            names: ["SNOVA_24_5_4_SHAKE_esk"],
+            public_key: 1016,
+            secret_key: 36_848,
+            signature: 248,
+            standardized: false,
+        }
+        /// SNOVA_37_17_2 (claimed NIST level 1; not standardized)
+        Snova37_17_2 {
+            names: ["SNOVA_37_17_2"],
+            public_key: 9842,
+            secret_key: 48,
+            signature: 124,
+            standardized: false,
+        }
+        /// SNOVA_25_8_3 (claimed NIST level 1; not standardized)
+        Snova25_8_3 {
+            names: ["SNOVA_25_8_3"],
+            public_key: 2320,
+            secret_key: 48,
+            signature: 165,
+            standardized: false,
+        }
+        /// SNOVA_56_25_2 (claimed NIST level 3; not standardized)
+        Snova56_25_2 {
+            names: ["SNOVA_56_25_2"],
+            public_key: 31_266,
+            secret_key: 48,
+            signature: 178,
+            standardized: false,
+        }
+        /// SNOVA_49_11_3 (claimed NIST level 3; not standardized)
+        Snova49_11_3 {
+            names: ["SNOVA_49_11_3"],
+            public_key: 6006,
+            secret_key: 48,
+            signature: 286,
+            standardized: false,
+        }
+        /// SNOVA_37_8_4 (claimed NIST level 3; not standardized)
+        Snova37_8_4 {
+            names: ["SNOVA_37_8_4"],
+            public_key: 4112,
+            secret_key: 48,
+            signature: 376,
+            standardized: false,
+        }
+        /// SNOVA_24_5_5 (claimed NIST level 3; not standardized)
+        Snova24_5_5 {
+            names: ["SNOVA_24_5_5"],
+            public_key: 1579,
+            secret_key: 48,
+            signature: 379,
+            standardized: false,
+        }
+        /// SNOVA_60_10_4 (claimed NIST level 5; not standardized)
+        Snova60_10_4 {
+            names: ["SNOVA_60_10_4"],
+            public_key: 8016,
+            secret_key: 48,
+            signature: 576,
+            standardized: false,
+        }
+        /// SNOVA_29_6_5 (claimed NIST level 5; not standardized)
+        Snova29_6_5 {
+            names: ["SNOVA_29_6_5"],
+            public_key: 2716,
+            secret_key: 48,
+            signature: 454,
+            standardized: false,
+        }
+    }
+}
+
+// ---- TEST-ONLY CONSTRUCTORS (gated behind the "testing" feature) ----
+#[cfg(feature = "testing")]
+impl PublicKey {
+    /// Construct without size checks (tests only).
+    pub fn from_bytes_unchecked(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+}
+
+#[cfg(feature = "testing")]
+impl SecretKey {
+    /// Construct without size checks (tests only).
+    pub fn from_bytes_unchecked(bytes: Vec<u8>) -> Self {
+        Self(bytes.into())
+    }
+}
+
+#[cfg(feature = "testing")]
+impl Signature {
+    /// Construct without size checks (tests only).
+    pub fn from_bytes_unchecked(bytes: Vec<u8>) -> Self {
+        Self(bytes)
     }
 }
@@ -0,0 +1,177 @@
+// Copyright (c) 2025 Orlando Trajano
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! RustCrypto [`signature`] 2.x adapters.
+//!
+//! | type               | implements                               |
+//! |--------------------|------------------------------------------|
+//! | [`SigningKey<S>`]  | `Signer<Signature>`, `Keypair`           |
+//! | [`VerifyingKey<S>`]| `Verifier<Signature>`, `AsRef<[u8]>`     |
+//! | [`Signature`]      | `SignatureEncoding` (`Repr = Vec<u8>`)   |
+//!
+//! `S` is any [`SignatureScheme`], so new algorithms are covered as they are
+//! added. Keys are length-checked against `S` on construction.
+
+use super::{PublicKey, SecretKey, Signature, SignatureScheme};
+use crate::OqsError;
+use core::fmt;
+use core::marker::PhantomData;
+
+/// Secret half of an `S` key pair, usable wherever a [`signature::Signer`]
+/// is expected.
+pub struct SigningKey<S> {
+    secret: SecretKey,
+    verifying: VerifyingKey<S>,
+}
+
+/// Public half of an `S` key pair, usable wherever a
+/// [`signature::Verifier`] is expected.
+pub struct VerifyingKey<S> {
+    public: PublicKey,
+    _scheme: PhantomData<S>,
+}
+
+impl<S: SignatureScheme> SigningKey<S> {
+    /// Generates a fresh key pair.
+    pub fn generate() -> Result<Self, OqsError> {
+        let (pk, sk) = S::keypair()?;
+        Self::from_keypair(pk, sk)
+    }
+
+    /// Wraps an existing key pair of the right lengths for `S`.
+    pub fn from_keypair(public: PublicKey, secret: SecretKey) -> Result<Self, OqsError> {
+        if secret.len() != S::SECRET_KEY_LEN {
+            return Err(OqsError::InvalidLength);
+        }
+        Ok(Self {
+            secret,
+            verifying: VerifyingKey::new(public)?,
+        })
+    }
+
+    /// The matching verifying key.
+    pub fn verifying_key(&self) -> &VerifyingKey<S> {
+        &self.verifying
+    }
+
+    /// The underlying secret key.
+    pub fn secret_key(&self) -> &SecretKey {
+        &self.secret
+    }
+}
+
+impl<S: SignatureScheme> VerifyingKey<S> {
+    /// Wraps a public key of the right length for `S`.
+    pub fn new(public: PublicKey) -> Result<Self, OqsError> {
+        if public.len() != S::PUBLIC_KEY_LEN {
+            return Err(OqsError::InvalidLength);
+        }
+        Ok(Self {
+            public,
+            _scheme: PhantomData,
+        })
+    }
+
+    /// The underlying public key.
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public
+    }
+}
+
+impl<S> Clone for SigningKey<S> {
+    fn clone(&self) -> Self {
+        Self {
+            secret: self.secret.clone(),
+            verifying: self.verifying.clone(),
+        }
+    }
+}
+
+impl<S> Clone for VerifyingKey<S> {
+    fn clone(&self) -> Self {
+        Self {
+            public: self.public.clone(),
+            _scheme: PhantomData,
+        }
+    }
+}
+
+impl<S: SignatureScheme> fmt::Debug for SigningKey<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SigningKey")
+            .field("scheme", &S::NAME)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S: SignatureScheme> fmt::Debug for VerifyingKey<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VerifyingKey")
+            .field("scheme", &S::NAME)
+            .field("public", &self.public)
+            .finish()
+    }
+}
+
+impl<S> PartialEq for VerifyingKey<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.public == other.public
+    }
+}
+
+impl<S> Eq for VerifyingKey<S> {}
+
+impl<S> AsRef<[u8]> for VerifyingKey<S> {
+    fn as_ref(&self) -> &[u8] {
+        self.public.as_bytes()
+    }
+}
+
+impl<S: SignatureScheme> TryFrom<&[u8]> for VerifyingKey<S> {
+    type Error = OqsError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, OqsError> {
+        Self::new(PublicKey(bytes.to_vec()))
+    }
+}
+
+impl<S: SignatureScheme> signature::Signer<Signature> for SigningKey<S> {
+    fn try_sign(&self, msg: &[u8]) -> Result<Signature, signature::Error> {
+        S::sign(&self.secret, msg).map_err(signature::Error::from_source)
+    }
+}
+
+impl<S: SignatureScheme> signature::Verifier<Signature> for VerifyingKey<S> {
+    fn verify(&self, msg: &[u8], sig: &Signature) -> Result<(), signature::Error> {
+        S::verify(&self.public, msg, sig).map_err(signature::Error::from_source)
+    }
+}
+
+impl<S: SignatureScheme> signature::Keypair for SigningKey<S> {
+    type VerifyingKey = VerifyingKey<S>;
+
+    fn verifying_key(&self) -> VerifyingKey<S> {
+        self.verifying.clone()
+    }
+}
+
+impl TryFrom<&[u8]> for Signature {
+    type Error = signature::Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, signature::Error> {
+        if bytes.is_empty() {
+            return Err(signature::Error::new());
+        }
+        Ok(Signature(bytes.to_vec()))
+    }
+}
+
+impl From<Signature> for Vec<u8> {
+    fn from(sig: Signature) -> Vec<u8> {
+        sig.0
+    }
+}
+
+impl signature::SignatureEncoding for Signature {
+    type Repr = Vec<u8>;
+}
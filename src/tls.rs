@@ -0,0 +1,225 @@
+// Copyright (c) 2025 Orlando Trajano
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! TLS 1.3 `key_share` helpers for the post-quantum and hybrid named groups
+//! (draft-ietf-tls-ecdhe-mlkem, draft-ietf-tls-mlkem).
+//!
+//! These produce and consume the exact `KeyShareEntry.key_exchange` bytes;
+//! the TLS record/handshake framing stays with the caller.
+//!
+//! | group               | code     | client share             | server share             | shared secret        |
+//! |---------------------|----------|--------------------------|--------------------------|----------------------|
+//! | `X25519MLKEM768`    | `0x11EC` | `ek (1184) \|\| x25519 (32)` | `ct (1088) \|\| x25519 (32)` | `ss_MLKEM \|\| ss_X25519` |
+//! | `SecP256r1MLKEM768` | `0x11EB` | `P-256 (65) \|\| ek (1184)`  | `P-256 (65) \|\| ct (1088)`  | `ss_ECDH \|\| ss_MLKEM`   |
+//! | `MLKEM1024`         | `0x0202` | `ek (1568)`              | `ct (1568)`              | `ss_MLKEM`           |
+//!
+//! Note the component order differs between the X25519 and P-256 hybrids.
+//! P-256 points are uncompressed SEC1 and the ECDH secret is the x-coordinate.
+
+use crate::hybrid::EcdhP256;
+use crate::kem::{Ciphertext, Kem, Kyber1024, Kyber768, PublicKey, SecretKey, SharedSecret};
+use crate::OqsError;
+use x25519_dalek::{x25519, X25519_BASEPOINT_BYTES};
+use zeroize::Zeroizing;
+
+/// Post-quantum and hybrid TLS 1.3 `NamedGroup`s.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NamedGroup {
+    /// `X25519MLKEM768` (`0x11EC`)
+    X25519MlKem768,
+    /// `SecP256r1MLKEM768` (`0x11EB`)
+    SecP256r1MlKem768,
+    /// `MLKEM1024` (`0x0202`)
+    MlKem1024,
+}
+
+impl NamedGroup {
+    /// IANA TLS `NamedGroup` codepoint.
+    pub const fn codepoint(self) -> u16 {
+        match self {
+            NamedGroup::X25519MlKem768 => 0x11EC,
+            NamedGroup::SecP256r1MlKem768 => 0x11EB,
+            NamedGroup::MlKem1024 => 0x0202,
+        }
+    }
+
+    /// Inverse of [`codepoint`](Self::codepoint).
+    pub const fn from_codepoint(code: u16) -> Option<Self> {
+        match code {
+            0x11EC => Some(NamedGroup::X25519MlKem768),
+            0x11EB => Some(NamedGroup::SecP256r1MlKem768),
+            0x0202 => Some(NamedGroup::MlKem1024),
+            _ => None,
+        }
+    }
+
+    /// IANA name, e.g. `"X25519MLKEM768"`.
+    pub const fn name(self) -> &'static str {
+        match self {
+            NamedGroup::X25519MlKem768 => "X25519MLKEM768",
+            NamedGroup::SecP256r1MlKem768 => "SecP256r1MLKEM768",
+            NamedGroup::MlKem1024 => "MLKEM1024",
+        }
+    }
+
+    /// Length of the ClientHello `key_exchange`.
+    pub const fn client_share_len(self) -> usize {
+        match self {
+            NamedGroup::X25519MlKem768 => Kyber768::PUBLIC_KEY_LEN + X25519_LEN,
+            NamedGroup::SecP256r1MlKem768 => EcdhP256::PUBLIC_KEY_LEN + Kyber768::PUBLIC_KEY_LEN,
+            NamedGroup::MlKem1024 => Kyber1024::PUBLIC_KEY_LEN,
+        }
+    }
+
+    /// Length of the ServerHello `key_exchange`.
+    pub const fn server_share_len(self) -> usize {
+        match self {
+            NamedGroup::X25519MlKem768 => Kyber768::CIPHERTEXT_LEN + X25519_LEN,
+            NamedGroup::SecP256r1MlKem768 => EcdhP256::CIPHERTEXT_LEN + Kyber768::CIPHERTEXT_LEN,
+            NamedGroup::MlKem1024 => Kyber1024::CIPHERTEXT_LEN,
+        }
+    }
+
+    /// Length of the shared secret fed into the TLS key schedule.
+    pub const fn shared_secret_len(self) -> usize {
+        match self {
+            NamedGroup::X25519MlKem768 | NamedGroup::SecP256r1MlKem768 => 64,
+            NamedGroup::MlKem1024 => 32,
+        }
+    }
+}
+
+const X25519_LEN: usize = 32;
+
+/// Client-side ephemeral secrets, consumed by [`client_shared_secret`].
+pub struct ClientKeyShareSecret {
+    group: NamedGroup,
+    mlkem: SecretKey,
+    classical: Zeroizing<Vec<u8>>,
+}
+
+impl ClientKeyShareSecret {
+    /// Group this secret belongs to.
+    pub fn group(&self) -> NamedGroup {
+        self.group
+    }
+}
+
+fn x25519_keypair() -> ([u8; 32], Zeroizing<Vec<u8>>) {
+    let mut sk = Zeroizing::new([0u8; 32]);
+    crate::rng::fill_bytes(&mut *sk);
+    (
+        x25519(*sk, X25519_BASEPOINT_BYTES),
+        Zeroizing::new(sk.to_vec()),
+    )
+}
+
+/// X25519 with the RFC 8446 §7.4.2 all-zero output check.
+fn x25519_shared(sk: &[u8], peer: &[u8]) -> Result<Zeroizing<[u8; 32]>, OqsError> {
+    let sk: [u8; 32] = sk.try_into().map_err(|_| OqsError::InvalidLength)?;
+    let peer: [u8; 32] = peer.try_into().map_err(|_| OqsError::InvalidLength)?;
+    let ss = Zeroizing::new(x25519(sk, peer));
+    if ss.iter().all(|&b| b == 0) {
+        return Err(OqsError::InvalidEncoding("X25519 point"));
+    }
+    Ok(ss)
+}
+
+/// Generates the ClientHello `key_exchange` for `group`.
+pub fn client_key_share(group: NamedGroup) -> Result<(Vec<u8>, ClientKeyShareSecret), OqsError> {
+    let (share, mlkem, classical) = match group {
+        NamedGroup::X25519MlKem768 => {
+            let (ek, dk) = Kyber768::keypair()?;
+            let (pk_x, sk_x) = x25519_keypair();
+            ([ek.as_bytes(), &pk_x].concat(), dk, sk_x)
+        }
+        NamedGroup::SecP256r1MlKem768 => {
+            let (ek, dk) = Kyber768::keypair()?;
+            let (pk_p, sk_p) = EcdhP256::keypair()?;
+            let sk_p = Zeroizing::new(sk_p.as_bytes().to_vec());
+            ([pk_p.as_bytes(), ek.as_bytes()].concat(), dk, sk_p)
+        }
+        NamedGroup::MlKem1024 => {
+            let (ek, dk) = Kyber1024::keypair()?;
+            (ek.as_bytes().to_vec(), dk, Zeroizing::new(Vec::new()))
+        }
+    };
+    Ok((
+        share,
+        ClientKeyShareSecret {
+            group,
+            mlkem,
+            classical,
+        },
+    ))
+}
+
+/// Server side: consumes the client's `key_exchange` and returns the
+/// ServerHello `key_exchange` plus the shared secret.
+pub fn server_key_share(
+    group: NamedGroup,
+    client_share: &[u8],
+) -> Result<(Vec<u8>, SharedSecret), OqsError> {
+    if client_share.len() != group.client_share_len() {
+        return Err(OqsError::InvalidLength);
+    }
+    match group {
+        NamedGroup::X25519MlKem768 => {
+            let (ek, pk_x) = client_share.split_at(Kyber768::PUBLIC_KEY_LEN);
+            let (ct, ss_m) = Kyber768::encapsulate(&PublicKey(ek.into()))?;
+            let (eph_x, sk_x) = x25519_keypair();
+            let ss_x = x25519_shared(&sk_x, pk_x)?;
+            Ok((
+                [ct.as_bytes(), &eph_x].concat(),
+                SharedSecret([ss_m.as_bytes(), &ss_x[..]].concat().into()),
+            ))
+        }
+        NamedGroup::SecP256r1MlKem768 => {
+            let (pk_p, ek) = client_share.split_at(EcdhP256::PUBLIC_KEY_LEN);
+            let (eph_p, ss_p) = EcdhP256::encapsulate(&PublicKey(pk_p.into()))?;
+            let (ct, ss_m) = Kyber768::encapsulate(&PublicKey(ek.into()))?;
+            Ok((
+                [eph_p.as_bytes(), ct.as_bytes()].concat(),
+                SharedSecret([ss_p.as_bytes(), ss_m.as_bytes()].concat().into()),
+            ))
+        }
+        NamedGroup::MlKem1024 => {
+            let (ct, ss) = Kyber1024::encapsulate(&PublicKey(client_share.into()))?;
+            Ok((ct.0, ss))
+        }
+    }
+}
+
+/// Client side: combines the ServerHello `key_exchange` with the secrets
+/// from [`client_key_share`].
+pub fn client_shared_secret(
+    secret: ClientKeyShareSecret,
+    server_share: &[u8],
+) -> Result<SharedSecret, OqsError> {
+    let group = secret.group;
+    if server_share.len() != group.server_share_len() {
+        return Err(OqsError::InvalidLength);
+    }
+    match group {
+        NamedGroup::X25519MlKem768 => {
+            let (ct, eph_x) = server_share.split_at(Kyber768::CIPHERTEXT_LEN);
+            let ss_m = Kyber768::decapsulate(&Ciphertext(ct.to_vec()), &secret.mlkem)?;
+            let ss_x = x25519_shared(&secret.classical, eph_x)?;
+            Ok(SharedSecret([ss_m.as_bytes(), &ss_x[..]].concat().into()))
+        }
+        NamedGroup::SecP256r1MlKem768 => {
+            let (eph_p, ct) = server_share.split_at(EcdhP256::CIPHERTEXT_LEN);
+            let ss_p = EcdhP256::decapsulate(
+                &Ciphertext(eph_p.to_vec()),
+                &SecretKey(secret.classical[..].into()),
+            )?;
+            let ss_m = Kyber768::decapsulate(&Ciphertext(ct.to_vec()), &secret.mlkem)?;
+            Ok(SharedSecret(
+                [ss_p.as_bytes(), ss_m.as_bytes()].concat().into(),
+            ))
+        }
+        NamedGroup::MlKem1024 => {
+            Kyber1024::decapsulate(&Ciphertext(server_share.to_vec()), &secret.mlkem)
+        }
+    }
+}
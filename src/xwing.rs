@@ -0,0 +1,134 @@
+// Copyright (c) 2025 Orlando Trajano
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! X-Wing hybrid KEM: X25519 + ML-KEM-768 (draft-connolly-cfrg-xwing-kem).
+//!
+//! The shared secret is `SHA3-256(ss_M || ss_X || ct_X || pk_X || XWingLabel)`,
+//! binding the X25519 ciphertext and public key so callers do not have to get
+//! the transcript right themselves. Secret keys are the 32-byte seed; the
+//! ML-KEM and X25519 keys are re-derived from it on decapsulation.
+
+use crate::kem::{Ciphertext, Kem, Kyber768, PublicKey, SecretKey, SharedSecret};
+use crate::OqsError;
+use sha3::digest::{ExtendableOutput, Update, XofReader};
+use sha3::{Digest, Sha3_256, Shake256};
+use x25519_dalek::{x25519, X25519_BASEPOINT_BYTES};
+use zeroize::Zeroizing;
+
+/// `XWingLabel`, the ASCII art `\.//^\`.
+const XWING_LABEL: &[u8; 6] = b"\\.//^\\";
+
+const X25519_LEN: usize = 32;
+
+/// X-Wing (X25519 + ML-KEM-768)
+pub struct XWing;
+
+struct DecapsulationKey {
+    sk_m: SecretKey,
+    sk_x: Zeroizing<[u8; X25519_LEN]>,
+    pk_m: PublicKey,
+    pk_x: [u8; X25519_LEN],
+}
+
+fn expand_decapsulation_key(seed: &[u8]) -> Result<DecapsulationKey, OqsError> {
+    let mut expanded = Zeroizing::new([0u8; 96]);
+    let mut shake = Shake256::default();
+    shake.update(seed);
+    shake.finalize_xof().read(&mut expanded[..]);
+
+    let mut d_z = Zeroizing::new([0u8; 64]);
+    d_z.copy_from_slice(&expanded[..64]);
+    let (pk_m, sk_m) = Kyber768::keypair_derand(&d_z)?;
+
+    let mut sk_x = Zeroizing::new([0u8; X25519_LEN]);
+    sk_x.copy_from_slice(&expanded[64..]);
+    let pk_x = x25519(*sk_x, X25519_BASEPOINT_BYTES);
+
+    Ok(DecapsulationKey {
+        sk_m,
+        sk_x,
+        pk_m,
+        pk_x,
+    })
+}
+
+fn combiner(ss_m: &[u8], ss_x: &[u8], ct_x: &[u8], pk_x: &[u8]) -> SharedSecret {
+    let mut h = Sha3_256::new();
+    Digest::update(&mut h, ss_m);
+    Digest::update(&mut h, ss_x);
+    Digest::update(&mut h, ct_x);
+    Digest::update(&mut h, pk_x);
+    Digest::update(&mut h, XWING_LABEL);
+    SharedSecret(h.finalize()[..].into())
+}
+
+impl XWing {
+    /// Deterministic key generation from a 32-byte seed (the secret key).
+    pub fn keypair_derand(seed: &[u8; 32]) -> Result<(PublicKey, SecretKey), OqsError> {
+        let dk = expand_decapsulation_key(seed)?;
+        let mut pk = Vec::with_capacity(Self::PUBLIC_KEY_LEN);
+        pk.extend_from_slice(dk.pk_m.as_bytes());
+        pk.extend_from_slice(&dk.pk_x);
+        Ok((PublicKey(pk.into()), SecretKey(seed[..].into())))
+    }
+
+    /// Deterministic encapsulation: `eseed[..32]` is the ML-KEM message,
+    /// `eseed[32..]` the ephemeral X25519 secret.
+    pub fn encapsulate_derand(
+        pk: &PublicKey,
+        eseed: &[u8; 64],
+    ) -> Result<(Ciphertext, SharedSecret), OqsError> {
+        if pk.len() != Self::PUBLIC_KEY_LEN {
+            return Err(OqsError::InvalidLength);
+        }
+        let (pk_m, pk_x) = pk.as_bytes().split_at(Kyber768::PUBLIC_KEY_LEN);
+        let pk_x: [u8; X25519_LEN] = pk_x.try_into().expect("length checked");
+
+        let mut ek_x = Zeroizing::new([0u8; X25519_LEN]);
+        ek_x.copy_from_slice(&eseed[32..]);
+        let ct_x = x25519(*ek_x, X25519_BASEPOINT_BYTES);
+        let ss_x = Zeroizing::new(x25519(*ek_x, pk_x));
+
+        let mut m = Zeroizing::new([0u8; 32]);
+        m.copy_from_slice(&eseed[..32]);
+        let (ct_m, ss_m) = Kyber768::encapsulate_derand(&PublicKey(pk_m.into()), &m)?;
+
+        let ss = combiner(ss_m.as_bytes(), &ss_x[..], &ct_x, &pk_x);
+        let mut ct = ct_m.0;
+        ct.extend_from_slice(&ct_x);
+        Ok((Ciphertext(ct), ss))
+    }
+}
+
+impl Kem for XWing {
+    const NAME: &'static str = "X-Wing";
+    const PUBLIC_KEY_LEN: usize = Kyber768::PUBLIC_KEY_LEN + X25519_LEN;
+    const SECRET_KEY_LEN: usize = 32;
+    const CIPHERTEXT_LEN: usize = Kyber768::CIPHERTEXT_LEN + X25519_LEN;
+    const SHARED_SECRET_LEN: usize = 32;
+
+    fn keypair() -> Result<(PublicKey, SecretKey), OqsError> {
+        let mut seed = Zeroizing::new([0u8; 32]);
+        crate::rng::fill_bytes(&mut seed[..]);
+        Self::keypair_derand(&seed)
+    }
+
+    fn encapsulate(pk: &PublicKey) -> Result<(Ciphertext, SharedSecret), OqsError> {
+        let mut eseed = Zeroizing::new([0u8; 64]);
+        crate::rng::fill_bytes(&mut eseed[..]);
+        Self::encapsulate_derand(pk, &eseed)
+    }
+
+    fn decapsulate(ct: &Ciphertext, sk: &SecretKey) -> Result<SharedSecret, OqsError> {
+        if ct.len() != Self::CIPHERTEXT_LEN || sk.len() != Self::SECRET_KEY_LEN {
+            return Err(OqsError::InvalidLength);
+        }
+        let dk = expand_decapsulation_key(sk.as_bytes())?;
+        let (ct_m, ct_x) = ct.as_bytes().split_at(Kyber768::CIPHERTEXT_LEN);
+        let ct_x: [u8; X25519_LEN] = ct_x.try_into().expect("length checked");
+
+        let ss_m = Kyber768::decapsulate(&Ciphertext(ct_m.to_vec()), &dk.sk_m)?;
+        let ss_x = Zeroizing::new(x25519(*dk.sk_x, ct_x));
+        Ok(combiner(ss_m.as_bytes(), &ss_x[..], &ct_x, &dk.pk_x))
+    }
+}
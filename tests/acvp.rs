@@ -0,0 +1,273 @@
+//! NIST ACVP conformance vectors (FIPS 203 / FIPS 204) from
+//! `liboqs/tests/ACVP_Vectors/*/internalProjection.json`, driven through the
+//! derandomized ML-KEM entry points and the ML-DSA context API.
+//!
+//! ML-DSA has no derandomized entry points in liboqs; like liboqs'
+//! `vectors_sig`, the tests feed the vector's `seed` / `rnd` to liboqs as its
+//! randomness, here through `rng::with_rng`.
+//!
+//! Only parameter sets oqs-safe exposes are checked (ML-KEM-768/1024,
+//! ML-DSA-44). Key-check groups, the ML-DSA internal interface and pre-hash
+//! signing have no oqs-safe API and are skipped. liboqs vendors the keyGen and
+//! encapDecap files only; the sigGen / sigVer tests are ignored by default and
+//! need `ACVP_Vectors/fetch_values.sh` to have downloaded theirs.
+
+#![cfg(feature = "testing")]
+
+use oqs_safe::kem::{Kem, Kyber1024, Kyber768};
+
+#[test]
+fn ml_kem_derand_is_deterministic() {
+    let seed = [7u8; 64];
+    let (pk, sk) = Kyber768::keypair_derand(&seed).unwrap();
+    let (pk2, _) = Kyber768::keypair_derand(&seed).unwrap();
+    assert_eq!(pk, pk2);
+
+    let (ct, ss) = Kyber768::encapsulate_derand(&pk, &[9u8; 32]).unwrap();
+    let (ct2, _) = Kyber768::encapsulate_derand(&pk, &[9u8; 32]).unwrap();
+    assert_eq!(ct, ct2);
+    assert!(Kyber768::decapsulate(&ct, &sk).unwrap() == ss);
+
+    let (pk, _) = Kyber1024::keypair_derand(&seed).unwrap();
+    assert_eq!(pk.len(), Kyber1024::PUBLIC_KEY_LEN);
+}
+
+#[cfg(feature = "liboqs")]
+mod vectors {
+    use super::*;
+    use oqs_safe::kem::{Ciphertext, PublicKey, SecretKey, SharedSecret};
+    use oqs_safe::rng::with_rng;
+    use oqs_safe::sig::{self, Dilithium2, SignatureScheme};
+    use oqs_safe::OqsError;
+    use rand_core::{CryptoRng, RngCore};
+    use serde_json::Value;
+
+    /// `internalProjection.json` of an ACVP vector set.
+    fn load(set: &str) -> Value {
+        let path = format!(
+            "{}/liboqs/tests/ACVP_Vectors/{set}/internalProjection.json",
+            env!("CARGO_MANIFEST_DIR")
+        );
+        let text = std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("{path}: {e}"));
+        serde_json::from_str(&text).expect("parse ACVP JSON")
+    }
+
+    /// Test groups for `parameter_set`, each with its test cases.
+    fn groups<'a>(
+        json: &'a Value,
+        parameter_set: &'a str,
+    ) -> impl Iterator<Item = (&'a Value, &'a Vec<Value>)> + 'a {
+        json["testGroups"]
+            .as_array()
+            .expect("testGroups")
+            .iter()
+            .filter(move |g| g["parameterSet"] == parameter_set)
+            .map(|g| (g, g["tests"].as_array().expect("tests")))
+    }
+
+    fn hex(test: &Value, field: &str) -> Vec<u8> {
+        let value = test[field]
+            .as_str()
+            .unwrap_or_else(|| panic!("tcId {}: no `{field}`", test["tcId"]));
+        hex::decode(value).unwrap_or_else(|e| panic!("tcId {}: {field}: {e}", test["tcId"]))
+    }
+
+    fn array<const N: usize>(test: &Value, field: &str) -> [u8; N] {
+        hex(test, field)
+            .try_into()
+            .unwrap_or_else(|_| panic!("tcId {}: `{field}` is not {N} bytes", test["tcId"]))
+    }
+
+    /// Hands liboqs exactly the vector's randomness, like `vectors_sig`'s
+    /// `MLDSA_randombytes`; drawing more than that is a test failure.
+    struct FixedRng(Vec<u8>);
+
+    impl RngCore for FixedRng {
+        fn next_u32(&mut self) -> u32 {
+            rand_core::impls::next_u32_via_fill(self)
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            rand_core::impls::next_u64_via_fill(self)
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            assert!(dest.len() <= self.0.len(), "vector randomness exhausted");
+            dest.copy_from_slice(&self.0[..dest.len()]);
+            self.0.drain(..dest.len());
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    impl CryptoRng for FixedRng {}
+
+    // ---------- ML-KEM (FIPS 203) ----------
+
+    type KeypairDerand = fn(&[u8; 64]) -> Result<(PublicKey, SecretKey), OqsError>;
+    type EncapsDerand = fn(&PublicKey, &[u8; 32]) -> Result<(Ciphertext, SharedSecret), OqsError>;
+
+    fn ml_kem_keygen<K: Kem>(derand: KeypairDerand) {
+        let json = load("ML-KEM-keyGen-FIPS203");
+        let mut cases = 0;
+        for (_, tests) in groups(&json, K::NAME) {
+            for test in tests {
+                let d: [u8; 32] = array(test, "d");
+                let z: [u8; 32] = array(test, "z");
+                let mut seed = [0u8; 64];
+                seed[..32].copy_from_slice(&d);
+                seed[32..].copy_from_slice(&z);
+
+                let (ek, dk) = derand(&seed).expect("keypair_derand");
+                assert_eq!(ek.as_bytes(), hex(test, "ek"), "tcId {}", test["tcId"]);
+                assert_eq!(dk.as_bytes(), hex(test, "dk"), "tcId {}", test["tcId"]);
+                cases += 1;
+            }
+        }
+        assert!(cases > 0, "no {} keyGen vectors", K::NAME);
+    }
+
+    fn ml_kem_encap_decap<K: Kem>(derand: EncapsDerand) {
+        let json = load("ML-KEM-encapDecap-FIPS203");
+        let (mut encaps, mut decaps) = (0, 0);
+        for (group, tests) in groups(&json, K::NAME) {
+            for test in tests {
+                let id = &test["tcId"];
+                let dk = SecretKey::from_bytes_unchecked(hex(test, "dk"));
+                let c = hex(test, "c");
+                let k = hex(test, "k");
+                match group["function"].as_str() {
+                    Some("encapsulation") => {
+                        let ek = PublicKey::from_bytes_unchecked(hex(test, "ek"));
+                        let (ct, ss) = derand(&ek, &array(test, "m")).expect("encapsulate_derand");
+                        assert_eq!(ct.as_bytes(), c, "tcId {id}");
+                        assert_eq!(ss.as_bytes(), k, "tcId {id}");
+                        encaps += 1;
+                    }
+                    // Covers valid and modified ciphertexts: the latter must
+                    // implicitly reject to the vector's pseudorandom key.
+                    Some("decapsulation") => {}
+                    // Key checks (FIPS 203 §7.2, §7.3) have no oqs-safe API.
+                    _ => continue,
+                }
+                let ss = K::decapsulate(&Ciphertext::from_bytes_unchecked(c), &dk)
+                    .unwrap_or_else(|e| panic!("tcId {id}: {e}"));
+                assert_eq!(ss.as_bytes(), k, "tcId {id}: {}", test["reason"]);
+                decaps += 1;
+            }
+        }
+        assert!(
+            encaps > 0 && decaps > encaps,
+            "no {} encapDecap vectors",
+            K::NAME
+        );
+    }
+
+    #[test]
+    fn ml_kem_768_acvp() {
+        ml_kem_keygen::<Kyber768>(Kyber768::keypair_derand);
+        ml_kem_encap_decap::<Kyber768>(Kyber768::encapsulate_derand);
+    }
+
+    #[test]
+    fn ml_kem_1024_acvp() {
+        ml_kem_keygen::<Kyber1024>(Kyber1024::keypair_derand);
+        ml_kem_encap_decap::<Kyber1024>(Kyber1024::encapsulate_derand);
+    }
+
+    // ---------- ML-DSA (FIPS 204) ----------
+
+    /// Groups exercising `ML-DSA.Sign` / `ML-DSA.Verify` (external, pure),
+    /// the only interface oqs-safe exposes.
+    fn external_pure(group: &Value) -> bool {
+        group["signatureInterface"] == "external" && group["preHash"] == "pure"
+    }
+
+    #[test]
+    fn ml_dsa_44_keygen_acvp() {
+        let json = load("ML-DSA-keyGen-FIPS204");
+        let mut cases = 0;
+        for (_, tests) in groups(&json, Dilithium2::NAME) {
+            for test in tests {
+                let mut rng = FixedRng(hex(test, "seed"));
+                let (pk, sk) = with_rng(&mut rng, Dilithium2::keypair).expect("keypair");
+                assert!(rng.0.is_empty(), "tcId {}: seed not consumed", test["tcId"]);
+                assert_eq!(pk.as_bytes(), hex(test, "pk"), "tcId {}", test["tcId"]);
+                assert_eq!(sk.as_bytes(), hex(test, "sk"), "tcId {}", test["tcId"]);
+                cases += 1;
+            }
+        }
+        assert!(cases > 0, "no ML-DSA-44 keyGen vectors");
+    }
+
+    #[test]
+    #[ignore = "run ACVP_Vectors/fetch_values.sh first"]
+    fn ml_dsa_44_siggen_acvp() {
+        let json = load("ML-DSA-sigGen-FIPS204");
+        let mut cases = 0;
+        for (group, tests) in groups(&json, Dilithium2::NAME) {
+            if !external_pure(group) {
+                continue;
+            }
+            for test in tests {
+                let id = &test["tcId"];
+                let sk = sig::SecretKey::from_bytes_unchecked(hex(test, "sk"));
+                let msg = hex(test, "message");
+                let ctx = hex(test, "context");
+                let rnd = if group["deterministic"] == true {
+                    vec![0u8; 32]
+                } else {
+                    hex(test, "rnd")
+                };
+
+                let signature = with_rng(&mut FixedRng(rnd), || {
+                    Dilithium2::sign_with_context(&sk, &msg, &ctx)
+                })
+                .unwrap_or_else(|e| panic!("tcId {id}: {e}"));
+                assert_eq!(signature.as_bytes(), hex(test, "signature"), "tcId {id}");
+                cases += 1;
+            }
+        }
+        assert!(cases > 0, "no ML-DSA-44 sigGen vectors");
+    }
+
+    #[test]
+    #[ignore = "run ACVP_Vectors/fetch_values.sh first"]
+    fn ml_dsa_44_sigver_acvp() {
+        let json = load("ML-DSA-sigVer-FIPS204");
+        let mut cases = 0;
+        for (group, tests) in groups(&json, Dilithium2::NAME) {
+            if !external_pure(group) {
+                continue;
+            }
+            for test in tests {
+                let id = &test["tcId"];
+                let pk = sig::PublicKey::from_bytes_unchecked(hex(test, "pk"));
+                let signature = sig::Signature::from_bytes_unchecked(hex(test, "signature"));
+                let result = Dilithium2::verify_with_context(
+                    &pk,
+                    &hex(test, "message"),
+                    &signature,
+                    &hex(test, "context"),
+                );
+                match (test["testPassed"].as_bool(), result) {
+                    (Some(true), Ok(())) => {}
+                    (Some(false), Err(OqsError::VerifyFail)) => {}
+                    (Some(false), Err(OqsError::InvalidLength))
+                        if signature.len() != Dilithium2::SIGNATURE_LEN => {}
+                    (expected, got) => {
+                        panic!(
+                            "tcId {id} ({}): expected {expected:?}, got {got:?}",
+                            test["reason"]
+                        )
+                    }
+                }
+                cases += 1;
+            }
+        }
+        assert!(cases > 0, "no ML-DSA-44 sigVer vectors");
+    }
+}
@@ -38,3 +38,123 @@ fn kyber768_rejects_bad_lengths() {
     let bad_sk = SecretKey::from_bytes_unchecked(vec![0u8; 456]);
     assert!(Kyber768::decapsulate(&bad_ct, &bad_sk).is_err());
 }
+
+#[test]
+fn debug_redacts_secrets() {
+    let (pk, sk) = Kyber768::keypair().unwrap();
+    let (_, ss) = Kyber768::encapsulate(&pk).unwrap();
+    let (_, sig_sk) = Dilithium2::keypair().unwrap();
+
+    for (debug, bytes) in [
+        (format!("{sk:?}"), sk.as_bytes()),
+        (format!("{ss:?}"), ss.as_bytes()),
+        (format!("{sig_sk:?}"), sig_sk.as_bytes()),
+    ] {
+        assert!(
+            debug.contains(&format!("<{} bytes>", bytes.len())),
+            "{debug}"
+        );
+        // `[b0, b1, b2, b3` as `{:?}` on the raw bytes would start.
+        let leading = format!("{:?}", &bytes[..4]);
+        assert!(!debug.contains(leading.trim_end_matches(']')), "{debug}");
+    }
+}
+
+#[cfg(feature = "mceliece")]
+#[test]
+fn mceliece348864_roundtrip_on_large_stack() {
+    use oqs_safe::kem::{keypair_on_large_stack, ClassicMcEliece348864};
+
+    let (pk, sk) = keypair_on_large_stack::<ClassicMcEliece348864>().unwrap();
+    assert_eq!(pk.len(), ClassicMcEliece348864::PUBLIC_KEY_LEN);
+    assert_eq!(sk.len(), ClassicMcEliece348864::SECRET_KEY_LEN);
+
+    // Clones share the (261 KB) buffer instead of copying it.
+    let shared = pk.clone();
+    assert!(std::sync::Arc::ptr_eq(&pk.to_shared(), &shared.to_shared()));
+
+    let (ct, ss1) = ClassicMcEliece348864::encapsulate(&pk).unwrap();
+    let ss2 = ClassicMcEliece348864::decapsulate(&ct, &sk).unwrap();
+    assert_eq!(ss1, ss2);
+    assert_eq!(ct.len(), ClassicMcEliece348864::CIPHERTEXT_LEN);
+}
+
+#[test]
+fn backend_info_matches_features() {
+    let info = oqs_safe::backend_info();
+    if cfg!(feature = "liboqs") {
+        assert_eq!(info.backend, oqs_safe::Backend::Liboqs);
+        assert!(info.liboqs_version.is_some());
+    } else {
+        assert_eq!(info.backend, oqs_safe::Backend::Mock);
+        assert!(!info.avx2);
+    }
+}
+
+#[cfg(feature = "bike")]
+#[test]
+fn bike_l1_roundtrip() {
+    use oqs_safe::kem::BikeL1;
+
+    let (pk, sk) = BikeL1::keypair().unwrap();
+    let (ct, ss1) = BikeL1::encapsulate(&pk).unwrap();
+    let ss2 = BikeL1::decapsulate(&ct, &sk).unwrap();
+    assert_eq!(ss1, ss2);
+}
+
+#[cfg(feature = "ntruprime")]
+#[test]
+fn sntrup761_roundtrip() {
+    use oqs_safe::kem::Sntrup761;
+
+    let (pk, sk) = Sntrup761::keypair().unwrap();
+    let (ct, ss1) = Sntrup761::encapsulate(&pk).unwrap();
+    let ss2 = Sntrup761::decapsulate(&ct, &sk).unwrap();
+    assert_eq!(ss1, ss2);
+    assert_eq!(pk.len(), 1158);
+    assert_eq!(ct.len(), 1039);
+}
+
+#[cfg(feature = "ntru")]
+#[test]
+fn ntru_roundtrips() {
+    use oqs_safe::kem::{
+        NtruHps2048509, NtruHps2048677, NtruHps40961229, NtruHps4096821, NtruHrss1373, NtruHrss701,
+    };
+
+    fn roundtrip<K: Kem>() {
+        let (pk, sk) = K::keypair().unwrap();
+        let (ct, ss1) = K::encapsulate(&pk).unwrap();
+        let ss2 = K::decapsulate(&ct, &sk).unwrap();
+        assert_eq!(ss1, ss2, "{}", K::NAME);
+        assert_eq!(pk.len(), K::PUBLIC_KEY_LEN, "{}", K::NAME);
+        assert_eq!(ct.len(), K::CIPHERTEXT_LEN, "{}", K::NAME);
+    }
+
+    roundtrip::<NtruHps2048509>();
+    roundtrip::<NtruHps2048677>();
+    roundtrip::<NtruHps4096821>();
+    roundtrip::<NtruHps40961229>();
+    roundtrip::<NtruHrss701>();
+    roundtrip::<NtruHrss1373>();
+}
+
+#[cfg(feature = "additional-signatures")]
+#[test]
+fn experimental_signatures_are_flagged() {
+    use oqs_safe::sig::experimental::{CrossRsdp128Fast, Mayo1, OvIsPkcSkc, Snova24_5_4};
+
+    fn standardized<S: SignatureScheme>() -> bool {
+        S::STANDARDIZED
+    }
+    assert!(standardized::<Dilithium2>());
+    assert!(!standardized::<Mayo1>());
+    assert!(!standardized::<OvIsPkcSkc>());
+    assert!(!standardized::<Snova24_5_4>());
+    assert!(!standardized::<CrossRsdp128Fast>());
+
+    let (pk, sk) = Mayo1::keypair().unwrap();
+    let sig = Mayo1::sign(&sk, b"evaluation lab").unwrap();
+    assert!(sig.len() <= Mayo1::SIGNATURE_LEN);
+    Mayo1::verify(&pk, b"evaluation lab", &sig).unwrap();
+}
@@ -0,0 +1,28 @@
+//! RNG wrapper for checking how much randomness an operation draws.
+
+use rand_chacha::rand_core::{CryptoRng, Error, RngCore};
+use rand_chacha::ChaCha20Rng;
+
+/// Counts the bytes drawn through it.
+pub struct Counting(pub ChaCha20Rng, pub usize);
+
+impl RngCore for Counting {
+    fn next_u32(&mut self) -> u32 {
+        self.1 += 4;
+        self.0.next_u32()
+    }
+    fn next_u64(&mut self) -> u64 {
+        self.1 += 8;
+        self.0.next_u64()
+    }
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.1 += dest.len();
+        self.0.fill_bytes(dest)
+    }
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl CryptoRng for Counting {}
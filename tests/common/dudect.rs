@@ -0,0 +1,128 @@
+//! Fixed-vs-random timing leakage detection after dudect (Reparaz, Balasch,
+//! Verbauwhede, "Dude, is my code constant time?", DATE 2017).
+//!
+//! Inputs of two classes are prepared up front, measured in random order,
+//! and the timings compared with Welch's t-test, once uncropped and once per
+//! upper-percentile cut (to discard interrupts and other one-sided noise).
+//! `|t| > 4.5` is dudect's threshold for "probably not constant time".
+
+use rand::Rng;
+use std::hint::black_box;
+use std::time::Instant;
+
+pub const T_THRESHOLD: f64 = 4.5;
+
+/// Percentiles at which the samples are additionally cropped.
+const CROPS: [f64; 6] = [0.5, 0.75, 0.9, 0.95, 0.99, 0.999];
+
+/// Welford running mean/variance, per class.
+#[derive(Default)]
+struct Welch {
+    n: [f64; 2],
+    mean: [f64; 2],
+    m2: [f64; 2],
+}
+
+impl Welch {
+    fn push(&mut self, class: usize, x: f64) {
+        self.n[class] += 1.0;
+        let d = x - self.mean[class];
+        self.mean[class] += d / self.n[class];
+        self.m2[class] += d * (x - self.mean[class]);
+    }
+
+    fn t(&self) -> f64 {
+        if self.n[0] < 2.0 || self.n[1] < 2.0 {
+            return 0.0;
+        }
+        let var = |c: usize| self.m2[c] / (self.n[c] - 1.0);
+        let se = (var(0) / self.n[0] + var(1) / self.n[1]).sqrt();
+        if se == 0.0 {
+            return 0.0;
+        }
+        (self.mean[0] - self.mean[1]) / se
+    }
+}
+
+/// Outcome of one [`measure`] run.
+#[derive(Debug)]
+pub struct Report {
+    pub samples: usize,
+    /// Largest `|t|` over the uncropped and cropped tests.
+    pub max_t: f64,
+}
+
+impl Report {
+    pub fn assert_constant_time(&self, what: &str) {
+        eprintln!(
+            "{what}: {} samples, max |t| = {:.2}",
+            self.samples, self.max_t
+        );
+        assert!(
+            self.max_t < T_THRESHOLD,
+            "{what}: timing depends on the input class (max |t| = {:.2} >= {T_THRESHOLD})",
+            self.max_t
+        );
+    }
+}
+
+/// Number of measurements: `DUDECT_SAMPLES` or `default`.
+pub fn samples(default: usize) -> usize {
+    std::env::var("DUDECT_SAMPLES")
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(default)
+}
+
+/// Times `op` on `samples` inputs from `prepare(class)` (class 0 = fixed,
+/// 1 = random), `reps` calls per measurement.
+pub fn measure<I, O>(
+    samples: usize,
+    reps: usize,
+    mut prepare: impl FnMut(usize) -> I,
+    mut op: impl FnMut(&I) -> O,
+) -> Report {
+    let mut rng = rand::thread_rng();
+    let inputs: Vec<(usize, I)> = (0..samples)
+        .map(|_| {
+            let class = rng.gen_range(0..2);
+            (class, prepare(class))
+        })
+        .collect();
+
+    // Warm caches and branch predictors before recording anything.
+    for (_, input) in inputs.iter().take(samples / 100 + 1) {
+        black_box(op(input));
+    }
+
+    let timings: Vec<(usize, u64)> = inputs
+        .iter()
+        .map(|(class, input)| {
+            let start = Instant::now();
+            for _ in 0..reps {
+                black_box(op(black_box(input)));
+            }
+            (*class, start.elapsed().as_nanos() as u64)
+        })
+        .collect();
+
+    let mut sorted: Vec<u64> = timings.iter().map(|t| t.1).collect();
+    sorted.sort_unstable();
+    let cutoffs = std::iter::once(u64::MAX).chain(
+        CROPS
+            .iter()
+            .map(|p| sorted[((sorted.len() - 1) as f64 * p) as usize]),
+    );
+
+    let max_t = cutoffs
+        .map(|cutoff| {
+            let mut w = Welch::default();
+            for &(class, t) in timings.iter().filter(|t| t.1 <= cutoff) {
+                w.push(class, t as f64);
+            }
+            w.t().abs()
+        })
+        .fold(0.0, f64::max);
+
+    Report { samples, max_t }
+}
@@ -0,0 +1,8 @@
+//! Helpers shared by the integration tests (`mod common;`).
+
+#![allow(dead_code)]
+
+pub mod counting;
+pub mod dudect;
+pub mod nist_drbg;
+pub mod rsp;
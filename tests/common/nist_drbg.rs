@@ -0,0 +1,97 @@
+//! NIST AES-256 CTR_DRBG as used by the PQC submission KAT generators
+//! (`rng.c`), mirroring liboqs' `OQS_randombytes_nist_kat`.
+//!
+//! liboqs does not export its own copy from shared builds, so the KAT tests
+//! scope this one over each operation with `oqs_safe::rng::with_rng`.
+
+use aes::cipher::{BlockEncrypt, KeyInit};
+use aes::{Aes256, Block};
+use rand::{CryptoRng, RngCore};
+
+#[derive(Clone)]
+pub struct NistDrbg {
+    key: [u8; 32],
+    v: [u8; 16],
+}
+
+impl NistDrbg {
+    /// `randombytes_init(entropy_input, NULL, 256)`.
+    pub fn new(entropy_input: &[u8; 48]) -> Self {
+        let mut drbg = NistDrbg {
+            key: [0u8; 32],
+            v: [0u8; 16],
+        };
+        drbg.update(Some(entropy_input));
+        drbg
+    }
+
+    pub fn fill(&mut self, out: &mut [u8]) {
+        for chunk in out.chunks_mut(16) {
+            let block = self.next_block();
+            chunk.copy_from_slice(&block[..chunk.len()]);
+        }
+        self.update(None);
+    }
+
+    fn next_block(&mut self) -> [u8; 16] {
+        for b in self.v.iter_mut().rev() {
+            *b = b.wrapping_add(1);
+            if *b != 0 {
+                break;
+            }
+        }
+        let mut block: Block = self.v.into();
+        Aes256::new(&self.key.into()).encrypt_block(&mut block);
+        block.into()
+    }
+
+    fn update(&mut self, provided: Option<&[u8; 48]>) {
+        let mut temp = [0u8; 48];
+        for chunk in temp.chunks_mut(16) {
+            chunk.copy_from_slice(&self.next_block());
+        }
+        if let Some(data) = provided {
+            for (t, d) in temp.iter_mut().zip(data) {
+                *t ^= d;
+            }
+        }
+        self.key.copy_from_slice(&temp[..32]);
+        self.v.copy_from_slice(&temp[32..]);
+    }
+}
+
+impl RngCore for NistDrbg {
+    fn next_u32(&mut self) -> u32 {
+        rand_core::impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        rand_core::impls::next_u64_via_fill(self)
+    }
+
+    /// One `randombytes` call: the DRBG state is updated once per request,
+    /// so callers must not split or merge requests.
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.fill(dest);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.fill(dest);
+        Ok(())
+    }
+}
+
+impl CryptoRng for NistDrbg {}
+
+#[test]
+fn matches_nist_rng_c_first_seed() {
+    // First `seed = ` line of every NIST PQC round-3 KAT `.rsp` file.
+    let entropy: [u8; 48] = core::array::from_fn(|i| i as u8);
+    let mut seed = [0u8; 48];
+    NistDrbg::new(&entropy).fill(&mut seed);
+    assert_eq!(
+        hex::encode_upper(seed),
+        "061550234D158C5EC95595FE04EF7A25767F2E24CC2BC479D09D86DC9ABCFDE7\
+         056A8C266F9EF97ED08541DBD2E1FFA1"
+    );
+}
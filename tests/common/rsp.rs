@@ -0,0 +1,93 @@
+//! Reader and writer for NIST PQC known-answer `.rsp` files: `key = value`
+//! lines, records separated by blank lines, `#` comment lines.
+//!
+//! Byte strings are upper-case hex, with an empty string written as `00`
+//! (`OQS_fprintBstr`, `fprintBstr` in the NIST generators).
+
+use std::fmt::Write;
+
+/// One `count = N` record, fields in file order.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Record(Vec<(String, String)>);
+
+impl Record {
+    pub fn fields(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.fields().find(|(k, _)| *k == key).map(|(_, v)| v)
+    }
+
+    pub fn int(&self, key: &str) -> usize {
+        let value = self.require(key);
+        value
+            .parse()
+            .unwrap_or_else(|_| panic!("{key} = {value}: not an integer"))
+    }
+
+    pub fn bytes(&self, key: &str) -> Vec<u8> {
+        let value = self.require(key);
+        hex::decode(value).unwrap_or_else(|e| panic!("{key}: {e}"))
+    }
+
+    fn require(&self, key: &str) -> &str {
+        self.get(key)
+            .unwrap_or_else(|| panic!("record has no `{key}` field"))
+    }
+}
+
+/// Parses every record of an `.rsp` file. Header comments and `[...]`
+/// section lines are skipped.
+pub fn parse(text: &str) -> Vec<Record> {
+    let mut records = Vec::new();
+    let mut current = Record::default();
+    for line in text.lines().map(str::trim) {
+        if line.is_empty() {
+            if !current.0.is_empty() {
+                records.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+        if line.starts_with('#') || line.starts_with('[') {
+            continue;
+        }
+        let (key, value) = line
+            .split_once('=')
+            .unwrap_or_else(|| panic!("malformed .rsp line: {line}"));
+        current
+            .0
+            .push((key.trim().to_owned(), value.trim().to_owned()));
+    }
+    if !current.0.is_empty() {
+        records.push(current);
+    }
+    records
+}
+
+pub fn write_int(out: &mut String, key: &str, value: usize) {
+    writeln!(out, "{key} = {value}").unwrap();
+}
+
+pub fn write_bytes(out: &mut String, key: &str, bytes: &[u8]) {
+    let hex = if bytes.is_empty() {
+        "00".to_owned()
+    } else {
+        hex::encode_upper(bytes)
+    };
+    writeln!(out, "{key} = {hex}").unwrap();
+}
+
+#[test]
+fn parses_records_and_comments() {
+    let text = "# ML-KEM-768\n\ncount = 0\nseed = 00FF\n\ncount = 1\nseed = 0102\n";
+    let records = parse(text);
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[1].int("count"), 1);
+    assert_eq!(records[0].bytes("seed"), [0x00, 0xff]);
+
+    let mut out = String::new();
+    write_int(&mut out, "count", 0);
+    write_bytes(&mut out, "seed", &[0x00, 0xff]);
+    assert_eq!(parse(&out)[0], records[0]);
+}
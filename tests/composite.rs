@@ -0,0 +1,97 @@
+#![cfg(feature = "composite")]
+
+use oqs_safe::composite::{MlDsa44EcdsaP256, MlDsa44Ed25519};
+use oqs_safe::rng::with_rng;
+use oqs_safe::sig::{PublicKey, SecretKey, Signature, SignatureScheme};
+use oqs_safe::OqsError;
+use rand_chacha::rand_core::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+
+fn roundtrip<S: SignatureScheme>() -> (PublicKey, SecretKey, Signature) {
+    let (pk, sk) = S::keypair().unwrap();
+    assert_eq!(pk.len(), S::PUBLIC_KEY_LEN);
+    assert_eq!(sk.len(), S::SECRET_KEY_LEN);
+    let sig = S::sign(&sk, b"composite").unwrap();
+    assert!(sig.len() <= S::SIGNATURE_LEN);
+    S::verify(&pk, b"composite", &sig).unwrap();
+    (pk, sk, sig)
+}
+
+/// Flips one byte of the traditional half (the signature tail).
+#[cfg(feature = "testing")]
+fn tamper_traditional(sig: &Signature) -> Signature {
+    let mut bytes = sig.as_bytes().to_vec();
+    let last = bytes.len() - 2;
+    bytes[last] ^= 0x01;
+    Signature::from_bytes_unchecked(bytes)
+}
+
+#[test]
+fn mldsa44_ed25519_roundtrip() {
+    let (pk, _, sig) = roundtrip::<MlDsa44Ed25519>();
+    assert_eq!(sig.len(), MlDsa44Ed25519::SIGNATURE_LEN);
+    assert!(matches!(
+        MlDsa44Ed25519::verify(&pk, b"other", &sig),
+        Err(OqsError::VerifyFail)
+    ));
+}
+
+#[test]
+fn mldsa44_ecdsa_p256_roundtrip() {
+    let (pk, _, sig) = roundtrip::<MlDsa44EcdsaP256>();
+    assert!(MlDsa44EcdsaP256::verify(&pk, b"other", &sig).is_err());
+}
+
+#[cfg(feature = "testing")]
+#[test]
+fn rejects_when_traditional_half_fails() {
+    let (pk, _, sig) = roundtrip::<MlDsa44Ed25519>();
+    assert!(MlDsa44Ed25519::verify(&pk, b"composite", &tamper_traditional(&sig)).is_err());
+
+    let (pk, _, sig) = roundtrip::<MlDsa44EcdsaP256>();
+    assert!(MlDsa44EcdsaP256::verify(&pk, b"composite", &tamper_traditional(&sig)).is_err());
+}
+
+#[cfg(all(feature = "liboqs", feature = "testing"))]
+#[test]
+fn rejects_when_mldsa_half_fails() {
+    let (pk, _, sig) = roundtrip::<MlDsa44Ed25519>();
+    let mut bytes = sig.as_bytes().to_vec();
+    bytes[0] ^= 0x01;
+    let sig = Signature::from_bytes_unchecked(bytes);
+    assert!(matches!(
+        MlDsa44Ed25519::verify(&pk, b"composite", &sig),
+        Err(OqsError::VerifyFail)
+    ));
+}
+
+#[test]
+fn context_is_bound() {
+    let (pk, sk) = MlDsa44Ed25519::keypair().unwrap();
+    let sig = MlDsa44Ed25519::sign_with_context(&sk, b"msg", b"app-1").unwrap();
+    MlDsa44Ed25519::verify_with_context(&pk, b"msg", &sig, b"app-1").unwrap();
+    assert!(MlDsa44Ed25519::verify_with_context(&pk, b"msg", &sig, b"app-2").is_err());
+    assert!(MlDsa44Ed25519::verify(&pk, b"msg", &sig).is_err());
+    assert!(matches!(
+        MlDsa44Ed25519::sign_with_context(&sk, b"msg", &[0u8; 256]),
+        Err(OqsError::InvalidLength)
+    ));
+}
+
+#[test]
+fn secret_key_holds_mldsa_seed() {
+    assert_eq!(MlDsa44Ed25519::SECRET_KEY_LEN, 32 + 32);
+    assert_eq!(MlDsa44EcdsaP256::SECRET_KEY_LEN, 32 + 32);
+
+    let keypair = || {
+        let mut rng = ChaCha20Rng::from_seed([5; 32]);
+        with_rng(&mut rng, MlDsa44Ed25519::keypair).unwrap()
+    };
+    let (pk1, sk1) = keypair();
+    let (pk2, sk2) = keypair();
+    assert_eq!(pk1, pk2);
+    assert_eq!(sk1.as_bytes(), sk2.as_bytes());
+
+    let sig = MlDsa44Ed25519::sign(&sk1, b"seed").unwrap();
+    MlDsa44Ed25519::verify(&pk2, b"seed", &sig).unwrap();
+}
@@ -0,0 +1,94 @@
+//! dudect-style timing checks for the wrapper layer, in the spirit of
+//! liboqs' `tests/constant_time` (which uses Valgrind instead).
+//!
+//! Statistical and machine-dependent, so ignored by default. Run on a quiet
+//! machine, in release mode, one test at a time:
+//!
+//! ```text
+//! cargo test --release --features liboqs,testing --test constant_time -- --ignored --test-threads=1
+//! ```
+//!
+//! `DUDECT_SAMPLES=<n>` overrides the number of measurements.
+
+#![cfg(feature = "testing")]
+
+mod common;
+
+use common::dudect::{measure, samples};
+use oqs_safe::kem::{Kem, Kyber768};
+
+/// `SharedSecret` equality: equal pairs vs pairs that differ. Both classes
+/// are built the same way so allocation patterns match.
+#[test]
+#[ignore = "timing test; run with --ignored --release"]
+fn shared_secret_eq_is_constant_time() {
+    use oqs_safe::kem::SharedSecret;
+    use rand::RngCore;
+
+    let (pk, _) = Kyber768::keypair().unwrap();
+    let (_, ss) = Kyber768::encapsulate(&pk).unwrap();
+    let mut rng = rand::thread_rng();
+    measure(
+        samples(200_000),
+        64,
+        |class| {
+            let mut bytes = ss.as_bytes().to_vec();
+            if class == 1 {
+                rng.fill_bytes(&mut bytes);
+            }
+            SharedSecret::from_bytes_unchecked(bytes)
+        },
+        |other| ss == *other,
+    )
+    .assert_constant_time("SharedSecret ==");
+}
+
+/// ML-KEM-768 decapsulation: a valid ciphertext vs random ones, which take
+/// the implicit-rejection path.
+#[cfg(feature = "liboqs")]
+#[test]
+#[ignore = "timing test; run with --ignored --release"]
+fn decapsulation_is_constant_time() {
+    use oqs_safe::kem::Ciphertext;
+    use rand::RngCore;
+
+    let (pk, sk) = Kyber768::keypair().unwrap();
+    let (ct, _) = Kyber768::encapsulate(&pk).unwrap();
+    let mut rng = rand::thread_rng();
+    measure(
+        samples(20_000),
+        1,
+        |class| match class {
+            0 => ct.clone(),
+            _ => {
+                let mut bytes = vec![0u8; Kyber768::CIPHERTEXT_LEN];
+                rng.fill_bytes(&mut bytes);
+                Ciphertext::from_bytes_unchecked(bytes)
+            }
+        },
+        |ct| Kyber768::decapsulate(ct, &sk).unwrap(),
+    )
+    .assert_constant_time("ML-KEM-768 decapsulate");
+}
+
+/// ML-DSA-44 signing: one fixed key vs keys drawn from a pool, same message.
+#[cfg(feature = "liboqs")]
+#[test]
+#[ignore = "timing test; run with --ignored --release"]
+fn signing_is_constant_time() {
+    use oqs_safe::sig::{Dilithium2, SignatureScheme};
+    use rand::Rng;
+
+    let keys: Vec<_> = (0..32).map(|_| Dilithium2::keypair().unwrap().1).collect();
+    let mut rng = rand::thread_rng();
+    measure(
+        samples(20_000),
+        1,
+        |class| match class {
+            0 => 0,
+            _ => rng.gen_range(0..keys.len()),
+        },
+        |&i| Dilithium2::sign(&keys[i], b"dudect").unwrap(),
+    )
+    .assert_constant_time("ML-DSA-44 sign");
+}
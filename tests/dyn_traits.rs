@@ -0,0 +1,90 @@
+use oqs_safe::kem::SharedSecret;
+use oqs_safe::kem::{Ciphertext, DynKem, Kem, Kyber1024, Kyber768, PublicKey, SecretKey};
+use oqs_safe::sig::{Dilithium2, DynSignatureScheme, SignatureScheme};
+use oqs_safe::OqsError;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+#[test]
+fn kems_behind_dyn() {
+    let kems: Vec<Box<dyn DynKem>> = vec![Box::new(Kyber768), Box::new(Kyber1024)];
+    for kem in &kems {
+        let (pk, sk) = kem.generate_keypair().unwrap();
+        assert_eq!(pk.len(), kem.public_key_len());
+        assert_eq!(sk.len(), kem.secret_key_len());
+        let (ct, ss) = kem.encaps(&pk).unwrap();
+        assert_eq!(ct.len(), kem.ciphertext_len());
+        assert_eq!(ss.len(), kem.shared_secret_len());
+        assert_eq!(kem.decaps(&ct, &sk).unwrap().as_bytes(), ss.as_bytes());
+    }
+    assert_eq!(kems[0].name(), Kyber768::NAME);
+
+    // Both traits in scope: the static API is unaffected.
+    let (pk, _) = Kyber768::keypair().unwrap();
+    assert_eq!(pk.len(), Kyber768::PUBLIC_KEY_LEN);
+}
+
+#[test]
+fn signatures_behind_dyn() {
+    let scheme: Arc<dyn DynSignatureScheme> = Arc::new(Dilithium2);
+    let (pk, sk) = scheme.generate_keypair().unwrap();
+    let sig = scheme.sign_message(&sk, b"msg").unwrap();
+    assert_eq!(sig.len(), scheme.signature_len());
+    scheme.verify_message(&pk, b"msg", &sig).unwrap();
+    assert_eq!(scheme.name(), Dilithium2::NAME);
+    assert!(scheme.is_standardized());
+
+    let t = std::thread::spawn(move || scheme.generate_keypair().is_ok());
+    assert!(t.join().unwrap());
+}
+
+/// A test double injected where a real KEM would be.
+struct FailingKem(AtomicUsize);
+
+impl DynKem for FailingKem {
+    fn name(&self) -> &'static str {
+        "failing"
+    }
+    fn public_key_len(&self) -> usize {
+        0
+    }
+    fn secret_key_len(&self) -> usize {
+        0
+    }
+    fn ciphertext_len(&self) -> usize {
+        0
+    }
+    fn shared_secret_len(&self) -> usize {
+        0
+    }
+    fn generate_keypair(&self) -> Result<(PublicKey, SecretKey), OqsError> {
+        Kyber768.generate_keypair()
+    }
+    fn encaps(&self, _: &PublicKey) -> Result<(Ciphertext, SharedSecret), OqsError> {
+        self.0.fetch_add(1, Ordering::Relaxed);
+        Err(OqsError::Backend {
+            op: "encaps",
+            status: -1,
+        })
+    }
+    fn decaps(&self, _: &Ciphertext, _: &SecretKey) -> Result<SharedSecret, OqsError> {
+        Err(OqsError::VerifyFail)
+    }
+}
+
+fn establish(kem: &dyn DynKem) -> Result<SharedSecret, OqsError> {
+    let (pk, sk) = kem.generate_keypair()?;
+    let (ct, _) = kem.encaps(&pk)?;
+    kem.decaps(&ct, &sk)
+}
+
+#[test]
+fn user_types_can_implement_dyn_kem() {
+    assert!(establish(&Kyber768).is_ok());
+    let double = FailingKem(AtomicUsize::new(0));
+    assert!(matches!(
+        establish(&double),
+        Err(OqsError::Backend { op: "encaps", .. })
+    ));
+    assert_eq!(double.0.load(Ordering::Relaxed), 1);
+}
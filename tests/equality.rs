@@ -0,0 +1,58 @@
+use oqs_safe::kem::{Kem, Kyber768};
+use oqs_safe::sig::{Dilithium2, SignatureScheme};
+use std::collections::HashSet;
+use subtle::ConstantTimeEq;
+
+#[test]
+fn shared_secrets_compare_by_value() {
+    let (pk, sk) = Kyber768::keypair().unwrap();
+    let (ct, ss) = Kyber768::encapsulate(&pk).unwrap();
+    let ss2 = Kyber768::decapsulate(&ct, &sk).unwrap();
+    assert!(ss == ss2);
+    assert_eq!(ss.ct_eq(&ss2).unwrap_u8(), 1);
+
+    let (_, other) = Kyber768::encapsulate(&pk).unwrap();
+    assert!(ss != other);
+    assert_eq!(ss.ct_eq(&other).unwrap_u8(), 0);
+}
+
+#[test]
+fn secret_keys_compare_by_value() {
+    let (_, sk) = Kyber768::keypair().unwrap();
+    let (_, sk2) = Kyber768::keypair().unwrap();
+    assert!(sk == sk.clone());
+    assert!(sk != sk2);
+
+    let (_, dsk) = Dilithium2::keypair().unwrap();
+    let (_, dsk2) = Dilithium2::keypair().unwrap();
+    assert!(dsk == dsk.clone());
+    assert!(dsk != dsk2);
+    assert_eq!(dsk.ct_eq(&dsk2).unwrap_u8(), 0);
+}
+
+#[cfg(feature = "testing")]
+#[test]
+fn different_lengths_are_unequal() {
+    use oqs_safe::kem::SharedSecret;
+    let a = SharedSecret::from_bytes_unchecked(vec![1; 32]);
+    let b = SharedSecret::from_bytes_unchecked(vec![1; 31]);
+    assert!(a != b);
+}
+
+#[test]
+fn public_types_are_hashable() {
+    let (pk, _) = Kyber768::keypair().unwrap();
+    let (pk2, _) = Kyber768::keypair().unwrap();
+    let set: HashSet<_> = [pk.clone(), pk.clone(), pk2].into_iter().collect();
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(&pk));
+
+    let (ct, _) = Kyber768::encapsulate(&pk).unwrap();
+    assert_eq!(ct, ct.clone());
+
+    let (vk, sk) = Dilithium2::keypair().unwrap();
+    let sig = Dilithium2::sign(&sk, b"msg").unwrap();
+    let set: HashSet<_> = [sig.clone(), sig].into_iter().collect();
+    assert_eq!(set.len(), 1);
+    assert_eq!(vk, vk.clone());
+}
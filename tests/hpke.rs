@@ -0,0 +1,195 @@
+#![cfg(feature = "hpke")]
+
+use oqs_safe::hpke::{
+    open_base, seal_base, setup_base_r, setup_base_s, setup_psk_r, setup_psk_s, Aead, Aes128Gcm,
+    Aes256Gcm, ChaCha20Poly1305, HpkeKem,
+};
+use oqs_safe::kem::{Kem, Kyber768};
+use oqs_safe::OqsError;
+
+fn roundtrip<K: HpkeKem, A: Aead>() {
+    let (pk, sk) = K::keypair().unwrap();
+    let (enc, ct) = seal_base::<K, A>(&pk, b"info", b"aad", b"hello hpke").unwrap();
+    assert_eq!(enc.len(), K::CIPHERTEXT_LEN);
+    let pt = open_base::<K, A>(&enc, &sk, b"info", b"aad", &ct).unwrap();
+    assert_eq!(pt, b"hello hpke");
+
+    assert!(matches!(
+        open_base::<K, A>(&enc, &sk, b"other info", b"aad", &ct),
+        Err(OqsError::VerifyFail)
+    ));
+    assert!(open_base::<K, A>(&enc, &sk, b"info", b"other aad", &ct).is_err());
+}
+
+#[test]
+fn mlkem768_all_aeads() {
+    roundtrip::<Kyber768, Aes128Gcm>();
+    roundtrip::<Kyber768, Aes256Gcm>();
+    roundtrip::<Kyber768, ChaCha20Poly1305>();
+}
+
+#[cfg(feature = "xwing")]
+#[test]
+fn xwing_roundtrip() {
+    roundtrip::<oqs_safe::xwing::XWing, ChaCha20Poly1305>();
+}
+
+#[test]
+fn multi_message_context_and_exporter() {
+    let (pk, sk) = Kyber768::keypair().unwrap();
+    let (enc, mut s) = setup_base_s::<Kyber768, Aes128Gcm>(&pk, b"info").unwrap();
+    let mut r = setup_base_r::<Kyber768, Aes128Gcm>(&enc, &sk, b"info").unwrap();
+
+    let c0 = s.seal(b"", b"m0").unwrap();
+    let c1 = s.seal(b"", b"m1").unwrap();
+    assert_ne!(c0, c1);
+    // A failed open leaves the sequence where it was.
+    assert!(r.open(b"", &c1).is_err());
+    assert_eq!(r.open(b"", &c0).unwrap(), b"m0");
+    assert_eq!(r.open(b"", &c1).unwrap(), b"m1");
+
+    assert_eq!(s.export(b"ctx", 48).unwrap(), r.export(b"ctx", 48).unwrap());
+    assert_ne!(
+        s.export(b"ctx", 32).unwrap(),
+        s.export(b"ctx2", 32).unwrap()
+    );
+}
+
+#[test]
+fn psk_mode() {
+    let psk = [0x42u8; 32];
+    let (pk, sk) = Kyber768::keypair().unwrap();
+    let (enc, mut s) = setup_psk_s::<Kyber768, Aes256Gcm>(&pk, b"", &psk, b"id").unwrap();
+    let ct = s.seal(b"", b"secret").unwrap();
+
+    let mut r = setup_psk_r::<Kyber768, Aes256Gcm>(&enc, &sk, b"", &psk, b"id").unwrap();
+    assert_eq!(r.open(b"", &ct).unwrap(), b"secret");
+
+    let mut wrong = setup_psk_r::<Kyber768, Aes256Gcm>(&enc, &sk, b"", &[0x43; 32], b"id").unwrap();
+    assert!(wrong.open(b"", &ct).is_err());
+}
+
+#[test]
+fn psk_mode_rejects_psk_without_id() {
+    let (pk, _) = Kyber768::keypair().unwrap();
+    assert!(matches!(
+        setup_psk_s::<Kyber768, Aes256Gcm>(&pk, b"", &[0x42; 32], b""),
+        Err(OqsError::Protocol(
+            "hpke: psk and psk_id must be given together"
+        ))
+    ));
+}
+
+#[test]
+fn psk_mode_rejects_missing_psk() {
+    let (pk, _) = Kyber768::keypair().unwrap();
+    assert!(matches!(
+        setup_psk_s::<Kyber768, Aes256Gcm>(&pk, b"", b"", b""),
+        Err(OqsError::Protocol("hpke: psk does not match mode"))
+    ));
+}
+
+#[test]
+fn psk_mode_rejects_short_psk() {
+    let (pk, sk) = Kyber768::keypair().unwrap();
+    assert!(matches!(
+        setup_psk_s::<Kyber768, Aes256Gcm>(&pk, b"", &[0x42; 31], b"id"),
+        Err(OqsError::Protocol("hpke: psk shorter than 32 bytes"))
+    ));
+    let (enc, _) = setup_base_s::<Kyber768, Aes256Gcm>(&pk, b"").unwrap();
+    assert!(matches!(
+        setup_psk_r::<Kyber768, Aes256Gcm>(&enc, &sk, b"", b"psk", b"id"),
+        Err(OqsError::Protocol("hpke: psk shorter than 32 bytes"))
+    ));
+}
+
+/// RFC 9180 Appendix A.1.1 (DHKEM(X25519), HKDF-SHA256, AES-128-GCM, Base),
+/// replayed through a KEM that returns the vector's `enc`/`shared_secret`.
+/// Checks the key schedule, nonce sequencing and exporter byte for byte.
+#[cfg(feature = "testing")]
+mod rfc9180 {
+    use super::*;
+    use oqs_safe::kem::{Ciphertext, PublicKey, SecretKey, SharedSecret};
+
+    const ENC: &str = "37fda3567bdbd628e88668c3c8d7e97d1d1253b6d4ea6d44c150f741f1bf4431";
+    const SHARED_SECRET: &str = "fe0e18c9f024ce43799ae393c7e8fe8fce9d218875e8227b0187c04e7d2ea1fc";
+
+    struct FixedX25519;
+
+    impl Kem for FixedX25519 {
+        const NAME: &'static str = "fixed-dhkem-x25519";
+        const PUBLIC_KEY_LEN: usize = 32;
+        const SECRET_KEY_LEN: usize = 32;
+        const CIPHERTEXT_LEN: usize = 32;
+        const SHARED_SECRET_LEN: usize = 32;
+
+        fn keypair() -> Result<(PublicKey, SecretKey), OqsError> {
+            Ok((
+                PublicKey::from_bytes_unchecked(vec![0; 32]),
+                SecretKey::from_bytes_unchecked(vec![0; 32]),
+            ))
+        }
+
+        fn encapsulate(_: &PublicKey) -> Result<(Ciphertext, SharedSecret), OqsError> {
+            Ok((
+                Ciphertext::from_bytes_unchecked(hex::decode(ENC).unwrap()),
+                SharedSecret::from_bytes_unchecked(hex::decode(SHARED_SECRET).unwrap()),
+            ))
+        }
+
+        fn decapsulate(_: &Ciphertext, _: &SecretKey) -> Result<SharedSecret, OqsError> {
+            Ok(SharedSecret::from_bytes_unchecked(
+                hex::decode(SHARED_SECRET).unwrap(),
+            ))
+        }
+    }
+
+    impl HpkeKem for FixedX25519 {
+        const KEM_ID: u16 = 0x0020;
+    }
+
+    #[test]
+    fn rfc9180_a_1_1() {
+        let info = hex::decode("4f6465206f6e2061204772656369616e2055726e").unwrap();
+        let pt = hex::decode("4265617574792069732074727574682c20747275746820626561757479").unwrap();
+        let (pk, sk) = FixedX25519::keypair().unwrap();
+
+        let (enc, mut s) = setup_base_s::<FixedX25519, Aes128Gcm>(&pk, &info).unwrap();
+        assert_eq!(hex::encode(&enc), ENC);
+        let ct0 = s
+            .seal(&hex::decode("436f756e742d30").unwrap(), &pt)
+            .unwrap();
+        assert_eq!(
+            hex::encode(&ct0),
+            "f938558b5d72f1a23810b4be2ab4f84331acc02fc97babc53a52ae8218a355a9\
+             6d8770ac83d07bea87e13c512a"
+        );
+        let ct1 = s
+            .seal(&hex::decode("436f756e742d31").unwrap(), &pt)
+            .unwrap();
+        assert_eq!(
+            hex::encode(&ct1),
+            "af2d7e9ac9ae7e270f46ba1f975be53c09f8d875bdc8535458c2494e8a6eab25\
+             1c03d0c22a56b8ca42c2063b84"
+        );
+
+        let r = setup_base_r::<FixedX25519, Aes128Gcm>(&enc, &sk, &info).unwrap();
+        for (ctx, expected) in [
+            (
+                "",
+                "3853fe2b4035195a573ffc53856e77058e15d9ea064de3e59f4961d0095250ee",
+            ),
+            (
+                "00",
+                "2e8f0b54673c7029649d4eb9d5e33bf1872cf76d623ff164ac185da9e88c21a5",
+            ),
+            (
+                "54657374436f6e74657874",
+                "e9e43065102c3836401bed8c3c3c75ae46be1639869391d62c61f1ec7af54931",
+            ),
+        ] {
+            let out = r.export(&hex::decode(ctx).unwrap(), 32).unwrap();
+            assert_eq!(hex::encode(out), expected);
+        }
+    }
+}
@@ -0,0 +1,78 @@
+#![cfg(feature = "hybrid")]
+
+use oqs_safe::hybrid::{Combiner, HybridKem, Sha3_256Combiner, Transcript};
+use oqs_safe::kem::{Kem, Kyber768};
+use zeroize::Zeroizing;
+
+#[cfg(feature = "frodokem")]
+type PqPair = HybridKem<Kyber768, oqs_safe::kem::FrodoKem640Shake>;
+#[cfg(not(feature = "frodokem"))]
+type PqPair = HybridKem<Kyber768, Kyber768>;
+
+#[test]
+fn hybrid_roundtrip_and_encoding() {
+    let (pk, sk) = PqPair::keypair().unwrap();
+    assert_eq!(pk.len(), PqPair::PUBLIC_KEY_LEN);
+    assert_eq!(sk.len(), PqPair::SECRET_KEY_LEN);
+    // The hybrid public key is the tail of the secret key encoding.
+    assert!(sk.as_bytes().ends_with(pk.as_bytes()));
+
+    let (ct, ss1) = PqPair::encapsulate(&pk).unwrap();
+    assert_eq!(ct.len(), PqPair::CIPHERTEXT_LEN);
+    let ss2 = PqPair::decapsulate(&ct, &sk).unwrap();
+    assert_eq!(ss1.as_bytes(), ss2.as_bytes());
+    assert_eq!(ss1.len(), Sha3_256Combiner::SHARED_SECRET_LEN);
+}
+
+/// A caller-defined combiner: HKDF-SHA256 over the same transcript.
+struct HkdfCombiner;
+
+impl Combiner for HkdfCombiner {
+    const NAME: &'static str = "test-hkdf-sha256";
+    const SHARED_SECRET_LEN: usize = 32;
+
+    fn combine(t: &Transcript<'_>) -> Zeroizing<Vec<u8>> {
+        let ikm = Zeroizing::new([t.ss_a, t.ss_b].concat());
+        let info = [t.ciphertext, t.public_key].concat();
+        let mut out = Zeroizing::new(vec![0u8; 32]);
+        hkdf::Hkdf::<sha2::Sha256>::new(None, &ikm)
+            .expand(&info, &mut out)
+            .unwrap();
+        out
+    }
+}
+
+#[test]
+fn hybrid_names_identify_components() {
+    type Default = HybridKem<Kyber768, Kyber768>;
+    type Custom = HybridKem<Kyber768, Kyber768, HkdfCombiner>;
+    assert_eq!(Default::NAME, "ML-KEM-768+ML-KEM-768/hybrid-sha3-256");
+    assert_ne!(Default::NAME, Custom::NAME);
+    assert_ne!(
+        HybridKem::<Kyber768, oqs_safe::kem::Kyber1024>::NAME,
+        HybridKem::<oqs_safe::kem::Kyber1024, Kyber768>::NAME
+    );
+}
+
+#[test]
+fn hybrid_with_custom_combiner() {
+    type K = HybridKem<Kyber768, Kyber768, HkdfCombiner>;
+    assert_eq!(K::NAME, "ML-KEM-768+ML-KEM-768/test-hkdf-sha256");
+    let (pk, sk) = K::keypair().unwrap();
+    let (ct, ss1) = K::encapsulate(&pk).unwrap();
+    assert_eq!(ss1.as_bytes(), K::decapsulate(&ct, &sk).unwrap().as_bytes());
+}
+
+#[cfg(feature = "ecdh_p256")]
+#[test]
+fn kyber768_plus_ecdh_p256() {
+    use oqs_safe::hybrid::EcdhP256;
+
+    type K = HybridKem<Kyber768, EcdhP256>;
+    assert_eq!(K::NAME, "ML-KEM-768+ECDH-P256/hybrid-sha3-256");
+    let (pk, sk) = K::keypair().unwrap();
+    assert_eq!(pk.len(), 1184 + 65);
+    let (ct, ss1) = K::encapsulate(&pk).unwrap();
+    assert_eq!(ct.len(), 1088 + 65);
+    assert_eq!(ss1.as_bytes(), K::decapsulate(&ct, &sk).unwrap().as_bytes());
+}
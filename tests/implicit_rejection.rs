@@ -0,0 +1,98 @@
+#![cfg(feature = "testing")]
+
+use oqs_safe::kem::{self, Ciphertext, DecapsOutcome, Kem, Kyber1024, Kyber768};
+
+fn tampered(ct: &Ciphertext, index: usize) -> Ciphertext {
+    let mut bytes = ct.as_bytes().to_vec();
+    bytes[index] ^= 0x01;
+    Ciphertext::from_bytes_unchecked(bytes)
+}
+
+/// Flips one bit at the start, middle and end of the ciphertext, and swaps
+/// in another recipient's key: every case must decapsulate without error to
+/// a secret that differs from the encapsulator's.
+fn check_implicit_rejection<K: Kem>() {
+    let (pk, sk) = K::keypair().unwrap();
+    let (ct, ss) = K::encapsulate(&pk).unwrap();
+
+    let outcome = kem::decapsulate_outcome::<K>(&ct, &sk, &ss).unwrap();
+    assert!(matches!(outcome, DecapsOutcome::Matched(_)), "{}", K::NAME);
+    assert!(outcome.into_secret() == ss);
+
+    for index in [0, K::CIPHERTEXT_LEN / 2, K::CIPHERTEXT_LEN - 1] {
+        let bad = tampered(&ct, index);
+        let got =
+            K::decapsulate(&bad, &sk).unwrap_or_else(|e| panic!("{}: byte {index}: {e}", K::NAME));
+        assert_eq!(got.len(), K::SHARED_SECRET_LEN);
+        assert!(got != ss, "{}: byte {index}", K::NAME);
+
+        // Rejection is deterministic for a given key and ciphertext.
+        assert!(K::decapsulate(&bad, &sk).unwrap() == got, "{}", K::NAME);
+        assert!(kem::decapsulate_outcome::<K>(&bad, &sk, &ss)
+            .unwrap()
+            .is_rejected());
+    }
+
+    let (_, other_sk) = K::keypair().unwrap();
+    let outcome = kem::decapsulate_outcome::<K>(&ct, &other_sk, &ss).unwrap();
+    assert!(outcome.is_rejected(), "{}: wrong key", K::NAME);
+}
+
+#[test]
+fn ml_kem_rejects_tampered_ciphertexts_implicitly() {
+    check_implicit_rejection::<Kyber768>();
+    check_implicit_rejection::<Kyber1024>();
+}
+
+#[test]
+fn wrong_length_is_still_an_error() {
+    let (pk, sk) = Kyber768::keypair().unwrap();
+    let (ct, _) = Kyber768::encapsulate(&pk).unwrap();
+    let short = Ciphertext::from_bytes_unchecked(ct.as_bytes()[1..].to_vec());
+    assert!(Kyber768::decapsulate(&short, &sk).is_err());
+}
+
+#[cfg(feature = "frodokem")]
+#[test]
+fn frodokem_rejects_tampered_ciphertexts_implicitly() {
+    check_implicit_rejection::<kem::FrodoKem640Aes>();
+    check_implicit_rejection::<kem::FrodoKem640Shake>();
+}
+
+#[cfg(feature = "bike")]
+#[test]
+fn bike_rejects_tampered_ciphertexts_implicitly() {
+    check_implicit_rejection::<kem::BikeL1>();
+}
+
+#[cfg(feature = "ntru")]
+#[test]
+fn ntru_rejects_tampered_ciphertexts_implicitly() {
+    check_implicit_rejection::<kem::NtruHps2048509>();
+    check_implicit_rejection::<kem::NtruHrss701>();
+}
+
+#[cfg(feature = "ntruprime")]
+#[test]
+fn sntrup_rejects_tampered_ciphertexts_implicitly() {
+    check_implicit_rejection::<kem::Sntrup761>();
+}
+
+#[cfg(feature = "mceliece")]
+#[test]
+fn mceliece_rejects_tampered_ciphertexts_implicitly() {
+    // Key generation needs a large stack.
+    std::thread::Builder::new()
+        .stack_size(kem::LARGE_STACK_SIZE)
+        .spawn(check_implicit_rejection::<kem::ClassicMcEliece348864>)
+        .unwrap()
+        .join()
+        .unwrap();
+}
+
+#[cfg(feature = "xwing")]
+#[test]
+fn xwing_rejects_tampered_ciphertexts_implicitly() {
+    // Covers both halves: the ML-KEM ciphertext and the X25519 share.
+    check_implicit_rejection::<oqs_safe::xwing::XWing>();
+}
@@ -0,0 +1,396 @@
+//! Known-answer tests: replays liboqs' `kat_kem` / `kat_sig` flow through the
+//! safe wrappers, compares the SHA-256 of the `.rsp`-formatted output against
+//! `liboqs/tests/KATs/{kem,sig}/kats.json`, then parses the output back and
+//! checks every record with `decapsulate` / `verify`.
+//!
+//! The default run covers the first record of each algorithm (`"single"`).
+//! The full files (`"all"`: 100 records, 10 for Classic McEliece) are
+//! `#[ignore]`d; run them with `cargo test --test kat -- --ignored`.
+//!
+//! Set `OQS_KAT_RSP_DIR` to a directory of NIST `.rsp` files named after the
+//! liboqs algorithm (e.g. `ML-KEM-768.rsp`) to also diff each record
+//! field-by-field against them.
+
+mod common;
+
+#[cfg(feature = "liboqs")]
+mod liboqs_kats {
+    use super::common::nist_drbg::NistDrbg;
+    use super::common::rsp::{self, Record};
+    #[cfg(feature = "testing")]
+    use oqs_safe::kem::Ciphertext;
+    use oqs_safe::kem::{self, Kem};
+    use oqs_safe::rng::with_rng;
+    use oqs_safe::sig::{self, SignatureScheme};
+    use sha2::{Digest, Sha256};
+
+    /// Which records of an algorithm's `.rsp` file to produce.
+    #[derive(Clone, Copy)]
+    enum Kats {
+        Single,
+        All,
+    }
+
+    impl Kats {
+        fn key(self) -> &'static str {
+            match self {
+                Kats::Single => "single",
+                Kats::All => "all",
+            }
+        }
+
+        fn count(self, name: &str) -> usize {
+            match self {
+                Kats::Single => 1,
+                Kats::All if name.starts_with("Classic-McEliece") => 10,
+                Kats::All => 100,
+            }
+        }
+    }
+
+    fn expected_digest(kind: &str, alg: &str, kats: Kats) -> String {
+        let path = format!(
+            "{}/liboqs/tests/KATs/{kind}/kats.json",
+            env!("CARGO_MANIFEST_DIR")
+        );
+        let json: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(path).expect("read kats.json"))
+                .expect("parse kats.json");
+        json[alg][kats.key()]
+            .as_str()
+            .unwrap_or_else(|| panic!("no {} KAT entry for {alg}", kats.key()))
+            .to_owned()
+    }
+
+    /// Records of `$OQS_KAT_RSP_DIR/<alg>.rsp`, if the variable is set.
+    fn reference_records(alg: &str) -> Option<Vec<Record>> {
+        let dir = std::env::var_os("OQS_KAT_RSP_DIR")?;
+        let path = std::path::Path::new(&dir).join(format!("{alg}.rsp"));
+        let text =
+            std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {e}", path.display()));
+        Some(rsp::parse(&text))
+    }
+
+    /// Hashes the generated records the way `test_kat.py` hashes the
+    /// generator's stdout, replays each one and diffs it against the
+    /// reference file, if any.
+    fn run(
+        kind: &str,
+        alg: &str,
+        kats: Kats,
+        mut record: impl FnMut(usize, &mut NistDrbg) -> String,
+        check: impl Fn(&Record),
+    ) {
+        let reference = reference_records(alg);
+        let entropy: [u8; 48] = core::array::from_fn(|i| i as u8);
+        let mut outer = NistDrbg::new(&entropy);
+        let mut hasher = Sha256::new();
+
+        let total = kats.count(alg);
+        for count in 0..total {
+            let mut text = record(count, &mut outer);
+            let parsed = rsp::parse(&text);
+            assert_eq!(parsed.len(), 1, "{alg}: record {count}");
+            check(&parsed[0]);
+            if let Some(reference) = &reference {
+                let want = reference
+                    .get(count)
+                    .unwrap_or_else(|| panic!("{alg}: reference has no record {count}"));
+                for (key, value) in parsed[0].fields() {
+                    if let Some(expected) = want.get(key) {
+                        assert!(
+                            value.eq_ignore_ascii_case(expected),
+                            "{alg}: record {count}: `{key}` differs from the reference"
+                        );
+                    }
+                }
+            }
+
+            // The NIST generators separate records with a blank line.
+            if count != total - 1 {
+                text.push('\n');
+            }
+            hasher.update(text);
+        }
+
+        let digest = hex::encode(hasher.finalize());
+        assert_eq!(
+            digest,
+            expected_digest(kind, alg, kats),
+            "{alg} {} KAT mismatch",
+            kats.key()
+        );
+    }
+
+    // ---------- KEM ----------
+
+    fn kem_record<K: Kem>(count: usize, outer: &mut NistDrbg) -> String {
+        let mut seed = [0u8; 48];
+        outer.fill(&mut seed);
+
+        let mut inner = NistDrbg::new(&seed);
+        let ((pk, sk), (ct, ss)) = with_rng(&mut inner, || {
+            // Classic McEliece needs the large stack; the scoped RNG follows.
+            let (pk, sk) = kem::keypair_on_large_stack::<K>().expect("keypair");
+            let encapsulated = K::encapsulate(&pk).expect("encapsulate");
+            ((pk, sk), encapsulated)
+        });
+
+        let mut out = String::new();
+        rsp::write_int(&mut out, "count", count);
+        rsp::write_bytes(&mut out, "seed", &seed);
+        rsp::write_bytes(&mut out, "pk", pk.as_bytes());
+        rsp::write_bytes(&mut out, "sk", sk.as_bytes());
+        rsp::write_bytes(&mut out, "ct", ct.as_bytes());
+        rsp::write_bytes(&mut out, "ss", ss.as_bytes());
+        out
+    }
+
+    /// Decapsulates the recorded ciphertext with the recorded secret key
+    /// (needs the `testing` constructors).
+    fn kem_check<K: Kem>(record: &Record) {
+        assert_eq!(record.bytes("ss").len(), K::SHARED_SECRET_LEN);
+        #[cfg(feature = "testing")]
+        {
+            let sk = kem::SecretKey::from_bytes_unchecked(record.bytes("sk"));
+            let ct = Ciphertext::from_bytes_unchecked(record.bytes("ct"));
+            let ss = K::decapsulate(&ct, &sk).expect("decapsulate");
+            assert_eq!(
+                ss.as_bytes(),
+                record.bytes("ss"),
+                "{}: shared secret",
+                K::NAME
+            );
+        }
+    }
+
+    fn check_kem<K: Kem>(kats: Kats) {
+        run("kem", K::NAME, kats, kem_record::<K>, kem_check::<K>);
+    }
+
+    // ---------- signatures ----------
+
+    /// How `kat_sig`'s `combine_message_signature` lays out `sm`.
+    #[derive(Clone, Copy)]
+    enum SignedMessage {
+        /// `signature || msg` (ML-DSA, MAYO, SNOVA).
+        SigThenMsg,
+        /// `msg || signature` (CROSS, UOV).
+        #[cfg_attr(not(feature = "additional-signatures"), allow(dead_code))]
+        MsgThenSig,
+    }
+
+    fn sig_record<S: SignatureScheme>(
+        layout: SignedMessage,
+        count: usize,
+        outer: &mut NistDrbg,
+    ) -> String {
+        let mut seed = [0u8; 48];
+        outer.fill(&mut seed);
+        let mut msg = vec![0u8; 33 * (count + 1)];
+        outer.fill(&mut msg);
+
+        let mut inner = NistDrbg::new(&seed);
+        let (pk, sk, signature) = with_rng(&mut inner, || {
+            let (pk, sk) = S::keypair().expect("keypair");
+            let signature = S::sign(&sk, &msg).expect("sign");
+            (pk, sk, signature)
+        });
+        let sm = match layout {
+            SignedMessage::SigThenMsg => [signature.as_bytes(), &msg].concat(),
+            SignedMessage::MsgThenSig => [&msg, signature.as_bytes()].concat(),
+        };
+
+        let mut out = String::new();
+        rsp::write_int(&mut out, "count", count);
+        rsp::write_bytes(&mut out, "seed", &seed);
+        rsp::write_int(&mut out, "mlen", msg.len());
+        rsp::write_bytes(&mut out, "msg", &msg);
+        rsp::write_bytes(&mut out, "pk", pk.as_bytes());
+        rsp::write_bytes(&mut out, "sk", sk.as_bytes());
+        rsp::write_int(&mut out, "smlen", sm.len());
+        rsp::write_bytes(&mut out, "sm", &sm);
+        out
+    }
+
+    /// Splits the recorded `sm` and verifies it under the recorded key
+    /// (needs the `testing` constructors).
+    fn sig_check<S: SignatureScheme>(layout: SignedMessage, record: &Record) {
+        let msg = record.bytes("msg");
+        let sm = record.bytes("sm");
+        assert_eq!(msg.len(), record.int("mlen"));
+        assert_eq!(sm.len(), record.int("smlen"));
+        let signature = match layout {
+            SignedMessage::SigThenMsg => &sm[..sm.len() - msg.len()],
+            SignedMessage::MsgThenSig => &sm[msg.len()..],
+        };
+        assert_eq!(signature.len(), S::SIGNATURE_LEN, "{}", S::NAME);
+        #[cfg(feature = "testing")]
+        {
+            let pk = sig::PublicKey::from_bytes_unchecked(record.bytes("pk"));
+            let signature = sig::Signature::from_bytes_unchecked(signature.to_vec());
+            S::verify(&pk, &msg, &signature).expect("verify");
+        }
+    }
+
+    fn check_sig<S: SignatureScheme>(layout: SignedMessage, kats: Kats) {
+        run(
+            "sig",
+            S::NAME,
+            kats,
+            |count, outer| sig_record::<S>(layout, count, outer),
+            |record| sig_check::<S>(layout, record),
+        );
+    }
+
+    // ---------- algorithm lists ----------
+
+    fn kems(kats: Kats) {
+        use oqs_safe::kem::*;
+
+        check_kem::<Kyber768>(kats);
+        check_kem::<Kyber1024>(kats);
+
+        #[cfg(feature = "frodokem")]
+        {
+            check_kem::<FrodoKem640Aes>(kats);
+            check_kem::<FrodoKem640Shake>(kats);
+            check_kem::<FrodoKem976Aes>(kats);
+            check_kem::<FrodoKem976Shake>(kats);
+            check_kem::<FrodoKem1344Aes>(kats);
+            check_kem::<FrodoKem1344Shake>(kats);
+        }
+
+        #[cfg(feature = "bike")]
+        {
+            check_kem::<BikeL1>(kats);
+            check_kem::<BikeL3>(kats);
+            check_kem::<BikeL5>(kats);
+        }
+
+        #[cfg(feature = "ntru")]
+        {
+            check_kem::<NtruHps2048509>(kats);
+            check_kem::<NtruHps2048677>(kats);
+            check_kem::<NtruHps4096821>(kats);
+            check_kem::<NtruHps40961229>(kats);
+            check_kem::<NtruHrss701>(kats);
+            check_kem::<NtruHrss1373>(kats);
+        }
+
+        #[cfg(feature = "ntruprime")]
+        check_kem::<Sntrup761>(kats);
+    }
+
+    #[cfg(feature = "mceliece")]
+    fn mceliece_kems(kats: Kats) {
+        use oqs_safe::kem::*;
+
+        check_kem::<ClassicMcEliece348864>(kats);
+        check_kem::<ClassicMcEliece348864f>(kats);
+        check_kem::<ClassicMcEliece460896>(kats);
+        check_kem::<ClassicMcEliece460896f>(kats);
+        check_kem::<ClassicMcEliece6688128>(kats);
+        check_kem::<ClassicMcEliece6688128f>(kats);
+        check_kem::<ClassicMcEliece6960119>(kats);
+        check_kem::<ClassicMcEliece6960119f>(kats);
+        check_kem::<ClassicMcEliece8192128>(kats);
+        check_kem::<ClassicMcEliece8192128f>(kats);
+    }
+
+    fn sigs(kats: Kats) {
+        use SignedMessage::*;
+
+        check_sig::<sig::Dilithium2>(SigThenMsg, kats);
+
+        #[cfg(feature = "additional-signatures")]
+        {
+            use oqs_safe::sig::experimental::*;
+
+            check_sig::<Mayo1>(SigThenMsg, kats);
+            check_sig::<Mayo2>(SigThenMsg, kats);
+            check_sig::<Mayo3>(SigThenMsg, kats);
+            check_sig::<Mayo5>(SigThenMsg, kats);
+
+            check_sig::<CrossRsdp128Balanced>(MsgThenSig, kats);
+            check_sig::<CrossRsdp128Fast>(MsgThenSig, kats);
+            check_sig::<CrossRsdp128Small>(MsgThenSig, kats);
+            check_sig::<CrossRsdp192Balanced>(MsgThenSig, kats);
+            check_sig::<CrossRsdp192Fast>(MsgThenSig, kats);
+            check_sig::<CrossRsdp192Small>(MsgThenSig, kats);
+            check_sig::<CrossRsdp256Balanced>(MsgThenSig, kats);
+            check_sig::<CrossRsdp256Fast>(MsgThenSig, kats);
+            check_sig::<CrossRsdp256Small>(MsgThenSig, kats);
+            check_sig::<CrossRsdpg128Balanced>(MsgThenSig, kats);
+            check_sig::<CrossRsdpg128Fast>(MsgThenSig, kats);
+            check_sig::<CrossRsdpg128Small>(MsgThenSig, kats);
+            check_sig::<CrossRsdpg192Balanced>(MsgThenSig, kats);
+            check_sig::<CrossRsdpg192Fast>(MsgThenSig, kats);
+            check_sig::<CrossRsdpg192Small>(MsgThenSig, kats);
+            check_sig::<CrossRsdpg256Balanced>(MsgThenSig, kats);
+            check_sig::<CrossRsdpg256Fast>(MsgThenSig, kats);
+            check_sig::<CrossRsdpg256Small>(MsgThenSig, kats);
+
+            check_sig::<OvIs>(MsgThenSig, kats);
+            check_sig::<OvIp>(MsgThenSig, kats);
+            check_sig::<OvIII>(MsgThenSig, kats);
+            check_sig::<OvV>(MsgThenSig, kats);
+            check_sig::<OvIsPkc>(MsgThenSig, kats);
+            check_sig::<OvIpPkc>(MsgThenSig, kats);
+            check_sig::<OvIIIPkc>(MsgThenSig, kats);
+            check_sig::<OvVPkc>(MsgThenSig, kats);
+            check_sig::<OvIsPkcSkc>(MsgThenSig, kats);
+            check_sig::<OvIpPkcSkc>(MsgThenSig, kats);
+            check_sig::<OvIIIPkcSkc>(MsgThenSig, kats);
+            check_sig::<OvVPkcSkc>(MsgThenSig, kats);
+
+            check_sig::<Snova24_5_4>(SigThenMsg, kats);
+            check_sig::<Snova24_5_4Shake>(SigThenMsg, kats);
+            check_sig::<Snova24_5_4Esk>(SigThenMsg, kats);
+            check_sig::<Snova24_5_4ShakeEsk>(SigThenMsg, kats);
+            check_sig::<Snova37_17_2>(SigThenMsg, kats);
+            check_sig::<Snova25_8_3>(SigThenMsg, kats);
+            check_sig::<Snova56_25_2>(SigThenMsg, kats);
+            check_sig::<Snova49_11_3>(SigThenMsg, kats);
+            check_sig::<Snova37_8_4>(SigThenMsg, kats);
+            check_sig::<Snova24_5_5>(SigThenMsg, kats);
+            check_sig::<Snova60_10_4>(SigThenMsg, kats);
+            check_sig::<Snova29_6_5>(SigThenMsg, kats);
+        }
+    }
+
+    #[test]
+    fn kem_kats_single() {
+        kems(Kats::Single);
+    }
+
+    #[test]
+    #[ignore = "full KAT files; slow"]
+    fn kem_kats_all() {
+        kems(Kats::All);
+    }
+
+    #[cfg(feature = "mceliece")]
+    #[test]
+    fn mceliece_kats_single() {
+        mceliece_kems(Kats::Single);
+    }
+
+    #[cfg(feature = "mceliece")]
+    #[test]
+    #[ignore = "full KAT files; slow"]
+    fn mceliece_kats_all() {
+        mceliece_kems(Kats::All);
+    }
+
+    #[test]
+    fn sig_kats_single() {
+        sigs(Kats::Single);
+    }
+
+    #[test]
+    #[ignore = "full KAT files; slow"]
+    fn sig_kats_all() {
+        sigs(Kats::All);
+    }
+}
@@ -0,0 +1,67 @@
+#![cfg(feature = "kem")]
+
+use kem::{Decapsulate, Encapsulate};
+use oqs_safe::kem::{Ciphertext, DecapsulationKey, EncapsulationKey, Kem, Kyber768, SharedSecret};
+use rand_chacha::rand_core::{OsRng, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+
+/// Code written against the RustCrypto traits only.
+fn roundtrip_generic<E, D>(ek: &E, dk: &D) -> (SharedSecret, SharedSecret)
+where
+    E: Encapsulate<Ciphertext, SharedSecret>,
+    D: Decapsulate<Ciphertext, SharedSecret>,
+{
+    let (ct, ss) = ek.encapsulate(&mut OsRng).unwrap();
+    (ss, dk.decapsulate(&ct).unwrap())
+}
+
+#[test]
+fn encapsulate_decapsulate_roundtrip() {
+    let dk = DecapsulationKey::<Kyber768>::generate().unwrap();
+    let (sent, received) = roundtrip_generic(dk.encapsulation_key(), &dk);
+    assert!(sent == received);
+    assert_eq!(sent.len(), Kyber768::SHARED_SECRET_LEN);
+}
+
+#[test]
+fn encapsulate_is_reproducible_from_rng() {
+    let dk = DecapsulationKey::<Kyber768>::generate().unwrap();
+    let ek = dk.encapsulation_key();
+    let (ct1, ss1) = ek
+        .encapsulate(&mut ChaCha20Rng::from_seed([7; 32]))
+        .unwrap();
+    let (ct2, ss2) = ek
+        .encapsulate(&mut ChaCha20Rng::from_seed([7; 32]))
+        .unwrap();
+    assert_eq!(ct1, ct2);
+    assert!(ss1 == ss2);
+    let (ct3, _) = ek
+        .encapsulate(&mut ChaCha20Rng::from_seed([8; 32]))
+        .unwrap();
+    assert_ne!(ct1, ct3);
+}
+
+#[test]
+fn keys_check_lengths() {
+    let (pk, sk) = Kyber768::keypair().unwrap();
+    let dk = DecapsulationKey::<Kyber768>::from_keypair(pk.clone(), sk.clone()).unwrap();
+    assert_eq!(dk.encapsulation_key().public_key(), &pk);
+
+    let ek = EncapsulationKey::<Kyber768>::try_from(pk.as_bytes()).unwrap();
+    assert_eq!(&ek, dk.encapsulation_key());
+    assert!(EncapsulationKey::<Kyber768>::try_from(&ek.as_ref()[1..]).is_err());
+    assert!(EncapsulationKey::<Kyber768>::try_from(sk.as_bytes()).is_err());
+    assert!(!format!("{dk:?}").contains("secret"));
+}
+
+#[cfg(feature = "testing")]
+#[test]
+fn decapsulate_rejects_wrong_length_ciphertext() {
+    let dk = DecapsulationKey::<Kyber768>::generate().unwrap();
+    let (ct, _) = dk.encapsulation_key().encapsulate(&mut OsRng).unwrap();
+    assert!(dk
+        .decapsulate(&Ciphertext::from_bytes_unchecked(
+            ct.as_bytes()[1..].to_vec()
+        ))
+        .is_err());
+}
@@ -0,0 +1,103 @@
+#![cfg(feature = "noise")]
+
+use oqs_safe::kem::{Kem, Kyber768};
+use oqs_safe::noise::{
+    Blake2b512, Blake2s256, CipherState, HandshakeState, NoiseHash, Pattern, Sha256,
+};
+use oqs_safe::OqsError;
+
+type Hs<H> = HandshakeState<Kyber768, H>;
+
+/// Drives a handshake to completion, checking each payload arrives intact.
+fn run<H: NoiseHash>(mut i: Hs<H>, mut r: Hs<H>) -> (Hs<H>, Hs<H>) {
+    let mut turn = 0u8;
+    while !i.is_finished() {
+        let (w, rd) = if i.is_my_turn() {
+            (&mut i, &mut r)
+        } else {
+            (&mut r, &mut i)
+        };
+        let payload = [turn; 5];
+        let msg = w.write_message(&payload).unwrap();
+        assert_eq!(rd.read_message(&msg).unwrap(), payload);
+        turn += 1;
+    }
+    assert!(r.is_finished());
+    assert_eq!(i.handshake_hash(), r.handshake_hash());
+    (i, r)
+}
+
+fn transport_roundtrip<H: NoiseHash>(i: Hs<H>, r: Hs<H>) {
+    let (mut i_tx, mut i_rx): (CipherState, CipherState) = i.into_transport().unwrap();
+    let (mut r_tx, mut r_rx) = r.into_transport().unwrap();
+    for n in 0..3u8 {
+        let ct = i_tx.encrypt_with_ad(b"", &[n; 10]).unwrap();
+        assert_eq!(r_rx.decrypt_with_ad(b"", &ct).unwrap(), [n; 10]);
+        let ct = r_tx.encrypt_with_ad(b"", b"pong").unwrap();
+        assert_eq!(i_rx.decrypt_with_ad(b"", &ct).unwrap(), b"pong");
+    }
+}
+
+fn pq_nk<H: NoiseHash>() {
+    let (rs_pk, rs_sk) = Kyber768::keypair().unwrap();
+    let i = Hs::<H>::initiator(Pattern::PqNK, b"prologue", None, Some(rs_pk.clone())).unwrap();
+    let r = Hs::<H>::responder(Pattern::PqNK, b"prologue", Some((rs_pk, rs_sk)), None).unwrap();
+    let (i, r) = run(i, r);
+    transport_roundtrip(i, r);
+}
+
+#[test]
+fn pq_nk_all_hashes() {
+    pq_nk::<Sha256>();
+    pq_nk::<Blake2s256>();
+    pq_nk::<Blake2b512>();
+}
+
+#[test]
+fn pq_xx_exchanges_static_keys() {
+    let (i_pk, i_sk) = Kyber768::keypair().unwrap();
+    let (r_pk, r_sk) = Kyber768::keypair().unwrap();
+    let i = Hs::<Sha256>::initiator(Pattern::PqXX, b"", Some((i_pk.clone(), i_sk)), None).unwrap();
+    let r = Hs::<Sha256>::responder(Pattern::PqXX, b"", Some((r_pk.clone(), r_sk)), None).unwrap();
+    let (i, r) = run(i, r);
+    assert_eq!(i.remote_static().unwrap().as_bytes(), r_pk.as_bytes());
+    assert_eq!(r.remote_static().unwrap().as_bytes(), i_pk.as_bytes());
+    transport_roundtrip(i, r);
+}
+
+#[test]
+fn mismatched_prologue_or_static_key_fails() {
+    let (rs_pk, rs_sk) = Kyber768::keypair().unwrap();
+    let (other_pk, _) = Kyber768::keypair().unwrap();
+
+    let mut i = Hs::<Sha256>::initiator(Pattern::PqNK, b"a", None, Some(rs_pk.clone())).unwrap();
+    let mut r = Hs::<Sha256>::responder(
+        Pattern::PqNK,
+        b"b",
+        Some((rs_pk.clone(), rs_sk.clone())),
+        None,
+    )
+    .unwrap();
+    let msg = i.write_message(b"hi").unwrap();
+    assert!(matches!(r.read_message(&msg), Err(OqsError::VerifyFail)));
+
+    let mut i = Hs::<Sha256>::initiator(Pattern::PqNK, b"", None, Some(other_pk)).unwrap();
+    let mut r = Hs::<Sha256>::responder(Pattern::PqNK, b"", Some((rs_pk, rs_sk)), None).unwrap();
+    let msg = i.write_message(b"hi").unwrap();
+    assert!(r.read_message(&msg).is_err());
+}
+
+#[test]
+fn state_machine_misuse_is_rejected() {
+    assert!(matches!(
+        Hs::<Sha256>::initiator(Pattern::PqNK, b"", None, None),
+        Err(OqsError::Protocol(_))
+    ));
+    assert!(Hs::<Sha256>::initiator(Pattern::PqXX, b"", None, None).is_err());
+
+    let (pk, sk) = Kyber768::keypair().unwrap();
+    let mut r = Hs::<Sha256>::responder(Pattern::PqXX, b"", Some((pk, sk)), None).unwrap();
+    assert!(matches!(r.write_message(b""), Err(OqsError::Protocol(_))));
+    assert!(r.read_message(&[0u8; 4]).is_err());
+    assert!(matches!(r.into_transport(), Err(OqsError::Protocol(_))));
+}
@@ -0,0 +1,29 @@
+//! One test on purpose: the random source is process-wide state.
+
+use oqs_safe::kem::{Kem, Kyber768};
+use oqs_safe::rng::{random_source, set_random_source, RandomSource};
+use oqs_safe::OqsError;
+
+#[test]
+fn source_is_pinned_by_first_use() {
+    assert_eq!(random_source(), RandomSource::System);
+
+    #[cfg(not(feature = "liboqs"))]
+    assert!(matches!(
+        set_random_source(RandomSource::OpenSsl),
+        Err(OqsError::NotImplemented)
+    ));
+
+    // The first operation fixes the (default) source on liboqs; on the mock
+    // backend pinning it explicitly has the same effect.
+    Kyber768::keypair().unwrap();
+    set_random_source(RandomSource::System).unwrap();
+
+    assert!(matches!(
+        set_random_source(RandomSource::OpenSsl),
+        Err(OqsError::Config(_))
+    ));
+    set_random_source(RandomSource::System).unwrap();
+    assert_eq!(random_source(), RandomSource::System);
+    Kyber768::keypair().unwrap();
+}
@@ -0,0 +1,26 @@
+#![cfg(feature = "liboqs")]
+//! Separate process from `random_source.rs`: pins OpenSSL before first use.
+
+use oqs_safe::kem::{Kem, Kyber768};
+use oqs_safe::rng::{random_source, set_random_source, RandomSource};
+use oqs_safe::OqsError;
+use rand_chacha::rand_core::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+
+#[test]
+#[ignore = "needs liboqs built with OQS_USE_OPENSSL"]
+fn openssl_source_rejects_scoped_rngs() {
+    set_random_source(RandomSource::OpenSsl).expect("liboqs built without OpenSSL");
+    assert_eq!(random_source(), RandomSource::OpenSsl);
+    Kyber768::keypair().unwrap();
+
+    let mut rng = ChaCha20Rng::from_seed([1; 32]);
+    assert!(matches!(
+        Kyber768::keypair_with_rng(&mut rng),
+        Err(OqsError::Config(_))
+    ));
+    assert!(matches!(
+        set_random_source(RandomSource::System),
+        Err(OqsError::Config(_))
+    ));
+}
@@ -0,0 +1,114 @@
+mod common;
+
+use common::counting::Counting;
+use oqs_safe::kem::{DynKem, Kem, Kyber768};
+use oqs_safe::rng::with_rng;
+use oqs_safe::sig::{Dilithium2, DynSignatureScheme, SignatureScheme};
+use rand_chacha::rand_core::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+
+fn seeded(n: u8) -> ChaCha20Rng {
+    ChaCha20Rng::from_seed([n; 32])
+}
+
+#[test]
+fn kem_is_reproducible_from_seed() {
+    let (pk1, sk1) = Kyber768::keypair_with_rng(&mut seeded(1)).unwrap();
+    let (pk2, sk2) = Kyber768::keypair_with_rng(&mut seeded(1)).unwrap();
+    let (pk3, _) = Kyber768::keypair_with_rng(&mut seeded(2)).unwrap();
+    assert_eq!(pk1.as_bytes(), pk2.as_bytes());
+    assert_eq!(sk1.as_bytes(), sk2.as_bytes());
+    assert_ne!(pk1.as_bytes(), pk3.as_bytes());
+
+    let (ct1, ss1) = Kyber768::encapsulate_with_rng(&pk1, &mut seeded(3)).unwrap();
+    let (ct2, ss2) = Kyber768::encapsulate_with_rng(&pk1, &mut seeded(3)).unwrap();
+    assert_eq!(ct1.as_bytes(), ct2.as_bytes());
+    assert_eq!(ss1.as_bytes(), ss2.as_bytes());
+    assert_eq!(
+        Kyber768::decapsulate(&ct1, &sk1).unwrap().as_bytes(),
+        ss1.as_bytes()
+    );
+}
+
+#[test]
+fn sig_is_reproducible_from_seed() {
+    let (pk, sk) = Dilithium2::keypair_with_rng(&mut seeded(4)).unwrap();
+    let (pk2, _) = Dilithium2::keypair_with_rng(&mut seeded(4)).unwrap();
+    assert_eq!(pk.as_bytes(), pk2.as_bytes());
+
+    let s1 = Dilithium2::sign_with_rng(&sk, b"msg", &mut seeded(5)).unwrap();
+    let s2 = Dilithium2::sign_with_rng(&sk, b"msg", &mut seeded(5)).unwrap();
+    assert_eq!(s1.as_bytes(), s2.as_bytes());
+    Dilithium2::verify(&pk, b"msg", &s1).unwrap();
+}
+
+#[test]
+fn dyn_traits_take_an_rng() {
+    let kem: &dyn DynKem = &Kyber768;
+    let mut rng = Counting(seeded(6), 0);
+    let (pk, _) = kem.generate_keypair_with_rng(&mut rng).unwrap();
+    assert!(rng.1 > 0);
+    let (pk2, _) = Kyber768::keypair_with_rng(&mut seeded(6)).unwrap();
+    assert_eq!(pk.as_bytes(), pk2.as_bytes());
+
+    let scheme: &dyn DynSignatureScheme = &Dilithium2;
+    let (pk, sk) = scheme.generate_keypair_with_rng(&mut seeded(12)).unwrap();
+    let (pk2, _) = Dilithium2::keypair_with_rng(&mut seeded(12)).unwrap();
+    assert_eq!(pk.as_bytes(), pk2.as_bytes());
+    let mut rng = Counting(seeded(13), 0);
+    let s1 = scheme.sign_message_with_rng(&sk, b"msg", &mut rng).unwrap();
+    assert!(rng.1 > 0);
+    let s2 = Dilithium2::sign_with_rng(&sk, b"msg", &mut seeded(13)).unwrap();
+    assert_eq!(s1.as_bytes(), s2.as_bytes());
+}
+
+#[test]
+fn rng_is_scoped_to_the_closure() {
+    let mut rng = Counting(seeded(7), 0);
+    let (pk1, _) = with_rng(&mut rng, Kyber768::keypair).unwrap();
+    let used = rng.1;
+    assert!(used > 0);
+
+    // Outside `with_rng` the OS RNG is back in charge.
+    let (pk2, _) = Kyber768::keypair().unwrap();
+    assert_ne!(pk1.as_bytes(), pk2.as_bytes());
+    assert_eq!(rng.1, used);
+}
+
+#[test]
+fn nested_scopes_restore_the_outer_rng() {
+    let mut outer = seeded(8);
+    let mut inner = seeded(9);
+    let (a, b, c) = with_rng(&mut outer, || {
+        let a = Kyber768::keypair().unwrap().0;
+        let b = with_rng(&mut inner, Kyber768::keypair).unwrap().0;
+        let c = Kyber768::keypair().unwrap().0;
+        (a, b, c)
+    });
+
+    let mut expect_outer = seeded(8);
+    let a2 = Kyber768::keypair_with_rng(&mut expect_outer).unwrap().0;
+    let c2 = Kyber768::keypair_with_rng(&mut expect_outer).unwrap().0;
+    let b2 = Kyber768::keypair_with_rng(&mut seeded(9)).unwrap().0;
+    assert_eq!(a.as_bytes(), a2.as_bytes());
+    assert_eq!(b.as_bytes(), b2.as_bytes());
+    assert_eq!(c.as_bytes(), c2.as_bytes());
+}
+
+#[cfg(feature = "xwing")]
+#[test]
+fn hybrids_draw_from_the_rng() {
+    use oqs_safe::xwing::XWing;
+    let (pk1, _) = XWing::keypair_with_rng(&mut seeded(10)).unwrap();
+    let (pk2, _) = XWing::keypair_with_rng(&mut seeded(10)).unwrap();
+    assert_eq!(pk1.as_bytes(), pk2.as_bytes());
+}
+
+#[cfg(feature = "ecdh_p256")]
+#[test]
+fn ecdh_p256_draws_from_the_rng() {
+    use oqs_safe::hybrid::EcdhP256;
+    let (pk1, _) = EcdhP256::keypair_with_rng(&mut seeded(11)).unwrap();
+    let (pk2, _) = EcdhP256::keypair_with_rng(&mut seeded(11)).unwrap();
+    assert_eq!(pk1.as_bytes(), pk2.as_bytes());
+}
@@ -0,0 +1,134 @@
+//! Stress tests for the per-thread RNG slot behind `rng::with_rng`.
+
+mod common;
+
+use common::counting::Counting;
+use oqs_safe::kem::{self, Kem, Kyber768};
+use oqs_safe::rng::with_rng;
+use oqs_safe::sig::{Dilithium2, SignatureScheme};
+use rand_chacha::rand_core::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+use std::collections::HashSet;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::Barrier;
+
+const THREADS: usize = 16;
+const ROUNDS: usize = 25;
+
+/// Everything one seeded thread produces, plus the bytes it drew.
+fn transcript(seed: u64) -> (Vec<Vec<u8>>, usize) {
+    let mut rng = Counting(ChaCha20Rng::seed_from_u64(seed), 0);
+    let mut out = Vec::new();
+    for _ in 0..ROUNDS {
+        let (pk, _) = Kyber768::keypair_with_rng(&mut rng).unwrap();
+        let (ct, _) = Kyber768::encapsulate_with_rng(&pk, &mut rng).unwrap();
+        let (_, sk) = Dilithium2::keypair_with_rng(&mut rng).unwrap();
+        let sig = Dilithium2::sign_with_rng(&sk, b"stress", &mut rng).unwrap();
+        out.push(pk.as_bytes().to_vec());
+        out.push(ct.as_bytes().to_vec());
+        out.push(sig.as_bytes().to_vec());
+    }
+    (out, rng.1)
+}
+
+#[test]
+fn concurrent_seeded_threads_match_sequential_runs() {
+    let expected: Vec<_> = (0..THREADS as u64).map(transcript).collect();
+
+    let barrier = Barrier::new(THREADS);
+    let got: Vec<_> = std::thread::scope(|s| {
+        let handles: Vec<_> = (0..THREADS as u64)
+            .map(|seed| {
+                let barrier = &barrier;
+                s.spawn(move || {
+                    barrier.wait();
+                    transcript(seed)
+                })
+            })
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    });
+
+    // Any cross-thread leak would change an output or a byte count.
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn unscoped_threads_never_draw_from_a_scoped_rng() {
+    let barrier = Barrier::new(THREADS);
+    let results: Vec<(bool, Vec<u8>)> = std::thread::scope(|s| {
+        let handles: Vec<_> = (0..THREADS)
+            .map(|i| {
+                let barrier = &barrier;
+                s.spawn(move || {
+                    barrier.wait();
+                    let mut keys = Vec::new();
+                    for _ in 0..ROUNDS {
+                        let pk = if i & 1 == 0 {
+                            // Every scoped thread uses the same seed.
+                            let mut rng = ChaCha20Rng::from_seed([42; 32]);
+                            Kyber768::keypair_with_rng(&mut rng).unwrap().0
+                        } else {
+                            Kyber768::keypair().unwrap().0
+                        };
+                        keys.push((i & 1 == 0, pk.as_bytes().to_vec()));
+                    }
+                    keys
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|h| h.join().unwrap())
+            .collect()
+    });
+
+    let seeded: HashSet<_> = results.iter().filter(|r| r.0).map(|r| &r.1).collect();
+    assert_eq!(seeded.len(), 1);
+    let unseeded: Vec<_> = results.iter().filter(|r| !r.0).map(|r| &r.1).collect();
+    let distinct: HashSet<_> = unseeded.iter().collect();
+    assert_eq!(distinct.len(), unseeded.len());
+    assert!(unseeded.iter().all(|pk| !seeded.contains(pk)));
+}
+
+#[test]
+fn panicking_closure_uninstalls_the_rng() {
+    let mut rng = Counting(ChaCha20Rng::seed_from_u64(7), 0);
+    let r = catch_unwind(AssertUnwindSafe(|| {
+        with_rng(&mut rng, || {
+            Kyber768::keypair().unwrap();
+            panic!("boom");
+        })
+    }));
+    assert!(r.is_err());
+    let used = rng.1;
+    assert!(used > 0);
+
+    Kyber768::keypair().unwrap();
+    assert_eq!(rng.1, used);
+}
+
+#[test]
+fn scoped_rng_follows_large_stack_keygen() {
+    let mut rng = ChaCha20Rng::seed_from_u64(9);
+    let (pk, _) = with_rng(&mut rng, || {
+        kem::keypair_with_stack_size::<Kyber768>(8 * 1024 * 1024)
+    })
+    .unwrap();
+    let (pk2, _) = Kyber768::keypair_with_rng(&mut ChaCha20Rng::seed_from_u64(9)).unwrap();
+    assert_eq!(pk.as_bytes(), pk2.as_bytes());
+
+    // And it is back on this thread afterwards.
+    let mut rng = Counting(ChaCha20Rng::seed_from_u64(9), 0);
+    with_rng(&mut rng, || {
+        kem::keypair_with_stack_size::<Kyber768>(8 * 1024 * 1024).unwrap();
+        Kyber768::keypair().unwrap();
+    });
+    assert_eq!(rng.1, 2 * bytes_per_keypair());
+}
+
+fn bytes_per_keypair() -> usize {
+    let mut rng = Counting(ChaCha20Rng::seed_from_u64(0), 0);
+    Kyber768::keypair_with_rng(&mut rng).unwrap();
+    rng.1
+}
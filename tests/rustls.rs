@@ -0,0 +1,222 @@
+#![cfg(feature = "rustls")]
+
+use oqs_safe::rustls::{
+    provider, MlDsa44SigningKey, ALL_KX_GROUPS, MLKEM1024, ML_DSA_44, SECP256R1_MLKEM768,
+    X25519_MLKEM768,
+};
+use oqs_safe::sig::{Dilithium2, SignatureScheme as _};
+use rustls::crypto::{ring, CryptoProvider, SupportedKxGroup};
+use rustls::pki_types::{alg_id, CertificateDer, ServerName};
+use rustls::sign::{CertifiedKey, SigningKey, SingleCertAndKey};
+use rustls::{ClientConfig, ClientConnection, NamedGroup, RootCertStore, ServerConfig};
+use rustls::{ServerConnection, SignatureScheme};
+use std::io::{Read, Write};
+use std::sync::Arc;
+
+fn der(tag: u8, body: &[u8]) -> Vec<u8> {
+    let mut out = vec![tag];
+    match body.len() {
+        n if n < 0x80 => out.push(n as u8),
+        n if n < 0x100 => out.extend([0x81, n as u8]),
+        n => out.extend([0x82, (n >> 8) as u8, n as u8]),
+    }
+    out.extend_from_slice(body);
+    out
+}
+
+/// A minimal self-signed ML-DSA-44 certificate for `localhost`.
+fn self_signed(key: &MlDsa44SigningKey, sk: &oqs_safe::sig::SecretKey) -> CertificateDer<'static> {
+    let alg = der(0x30, alg_id::ML_DSA_44.as_ref());
+    let cn = der(0x0c, b"localhost");
+    let name = der(
+        0x30,
+        &der(
+            0x31,
+            &der(0x30, &[&[0x06, 0x03, 0x55, 0x04, 0x03][..], &cn].concat()),
+        ),
+    );
+    let validity = der(
+        0x30,
+        &[der(0x17, b"250101000000Z"), der(0x18, b"20991231235959Z")].concat(),
+    );
+    let san = der(0x30, &der(0x82, b"localhost"));
+    let ext = der(
+        0xa3,
+        &der(
+            0x30,
+            &der(
+                0x30,
+                &[&[0x06, 0x03, 0x55, 0x1d, 0x11][..], &der(0x04, &san)].concat(),
+            ),
+        ),
+    );
+    let tbs = der(
+        0x30,
+        &[
+            &[0xa0, 0x03, 0x02, 0x01, 0x02][..],
+            &der(0x02, &[0x01, 0x02, 0x03, 0x04]),
+            &alg,
+            &name,
+            &validity,
+            &name,
+            key.spki().as_ref(),
+            &ext,
+        ]
+        .concat(),
+    );
+    let sig = Dilithium2::sign(sk, &tbs).unwrap();
+    let sig = der(0x03, &[&[0u8][..], sig.as_bytes()].concat());
+    CertificateDer::from(der(0x30, &[tbs, alg, sig].concat()))
+}
+
+fn configs(kx: &'static dyn SupportedKxGroup) -> (ClientConfig, ServerConfig) {
+    let (pk, sk) = Dilithium2::keypair().unwrap();
+    let key = MlDsa44SigningKey::new(pk, sk.clone()).unwrap();
+    let cert = self_signed(&key, &sk);
+
+    let provider = Arc::new(CryptoProvider {
+        kx_groups: vec![kx],
+        ..provider(ring::default_provider())
+    });
+
+    let mut roots = RootCertStore::empty();
+    roots.add(cert.clone()).unwrap();
+    let client = ClientConfig::builder_with_provider(provider.clone())
+        .with_protocol_versions(&[&rustls::version::TLS13])
+        .unwrap()
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+
+    let certified = CertifiedKey::new(vec![cert], Arc::new(key));
+    let server = ServerConfig::builder_with_provider(provider)
+        .with_protocol_versions(&[&rustls::version::TLS13])
+        .unwrap()
+        .with_no_client_auth()
+        .with_cert_resolver(Arc::new(SingleCertAndKey::from(certified)));
+    (client, server)
+}
+
+/// Shuttles TLS records between the two ends until both finish the handshake.
+fn handshake(client: &mut ClientConnection, server: &mut ServerConnection) {
+    for _ in 0..10 {
+        if !client.is_handshaking() && !server.is_handshaking() {
+            return;
+        }
+        let mut buf = Vec::new();
+        while client.wants_write() {
+            client.write_tls(&mut buf).unwrap();
+        }
+        let mut rd = &buf[..];
+        while !rd.is_empty() {
+            server.read_tls(&mut rd).unwrap();
+            server.process_new_packets().unwrap();
+        }
+
+        buf.clear();
+        while server.wants_write() {
+            server.write_tls(&mut buf).unwrap();
+        }
+        let mut rd = &buf[..];
+        while !rd.is_empty() {
+            client.read_tls(&mut rd).unwrap();
+            client.process_new_packets().unwrap();
+        }
+    }
+    panic!("handshake did not complete");
+}
+
+fn loopback(kx: &'static dyn SupportedKxGroup) {
+    let (client_cfg, server_cfg) = configs(kx);
+    let name = ServerName::try_from("localhost").unwrap();
+    let mut client = ClientConnection::new(Arc::new(client_cfg), name).unwrap();
+    let mut server = ServerConnection::new(Arc::new(server_cfg)).unwrap();
+    handshake(&mut client, &mut server);
+
+    assert_eq!(
+        client.negotiated_key_exchange_group().unwrap().name(),
+        kx.name()
+    );
+    assert_eq!(
+        server.negotiated_key_exchange_group().unwrap().name(),
+        kx.name()
+    );
+
+    client.writer().write_all(b"ping").unwrap();
+    let mut buf = Vec::new();
+    client.write_tls(&mut buf).unwrap();
+    server.read_tls(&mut &buf[..]).unwrap();
+    server.process_new_packets().unwrap();
+    let mut got = [0u8; 4];
+    server.reader().read_exact(&mut got).unwrap();
+    assert_eq!(&got, b"ping");
+}
+
+#[test]
+fn loopback_all_groups() {
+    for kx in ALL_KX_GROUPS {
+        loopback(*kx);
+    }
+}
+
+#[test]
+fn kx_group_codepoints() {
+    assert_eq!(X25519_MLKEM768.name(), NamedGroup::X25519MLKEM768);
+    assert_eq!(SECP256R1_MLKEM768.name(), NamedGroup::secp256r1MLKEM768);
+    assert_eq!(MLKEM1024.name(), NamedGroup::MLKEM1024);
+    for kx in ALL_KX_GROUPS {
+        assert!(kx.usable_for_version(rustls::ProtocolVersion::TLSv1_3));
+        assert!(!kx.usable_for_version(rustls::ProtocolVersion::TLSv1_2));
+    }
+
+    // PQ groups are preferred, classical ones follow.
+    let p = provider(ring::default_provider());
+    assert_eq!(p.kx_groups[0].name(), NamedGroup::X25519MLKEM768);
+    assert!(p.kx_groups.len() > ALL_KX_GROUPS.len());
+}
+
+#[test]
+fn kx_rejects_malformed_shares() {
+    let active = X25519_MLKEM768.start().unwrap();
+    let share = active.pub_key().to_vec();
+    assert!(X25519_MLKEM768.start_and_complete(&share[1..]).is_err());
+    assert!(active.complete(&[0u8; 7]).is_err());
+}
+
+#[test]
+fn signing_key_scheme_and_verification() {
+    let (pk, sk) = Dilithium2::keypair().unwrap();
+    let key = MlDsa44SigningKey::new(pk.clone(), sk).unwrap();
+
+    assert!(key.choose_scheme(&[SignatureScheme::ED25519]).is_none());
+    let signer = key
+        .choose_scheme(&[SignatureScheme::ED25519, SignatureScheme::ML_DSA_44])
+        .unwrap();
+    assert_eq!(signer.scheme(), SignatureScheme::ML_DSA_44);
+
+    let sig = signer.sign(b"transcript").unwrap();
+    assert_eq!(sig.len(), Dilithium2::SIGNATURE_LEN);
+    assert!(ML_DSA_44
+        .verify_signature(pk.as_bytes(), b"transcript", &sig)
+        .is_ok());
+    assert!(ML_DSA_44
+        .verify_signature(&pk.as_bytes()[1..], b"transcript", &sig)
+        .is_err());
+    assert!(ML_DSA_44
+        .verify_signature(pk.as_bytes(), b"transcript", &sig[1..])
+        .is_err());
+}
+
+#[cfg(feature = "liboqs")]
+#[test]
+fn verification_rejects_forgeries() {
+    let (pk, sk) = Dilithium2::keypair().unwrap();
+    let key = MlDsa44SigningKey::new(pk.clone(), sk).unwrap();
+    let sig = key
+        .choose_scheme(&[SignatureScheme::ML_DSA_44])
+        .unwrap()
+        .sign(b"transcript")
+        .unwrap();
+    assert!(ML_DSA_44
+        .verify_signature(pk.as_bytes(), b"other", &sig)
+        .is_err());
+}
@@ -0,0 +1,63 @@
+#![cfg(feature = "seal")]
+
+use oqs_safe::kem::{self, Kem, Kyber768};
+use oqs_safe::OqsError;
+
+#[test]
+fn seal_open_roundtrip() {
+    let (pk, sk) = Kyber768::keypair().unwrap();
+    let env = kem::seal::<Kyber768>(&pk, b"blob", b"aad").unwrap();
+    assert_eq!(&env[..4], b"OQSE");
+    assert_eq!(kem::envelope_algorithm(&env).unwrap(), Kyber768::NAME);
+    assert_eq!(kem::open::<Kyber768>(&sk, &env, b"aad").unwrap(), b"blob");
+
+    let empty = kem::seal::<Kyber768>(&pk, b"", b"").unwrap();
+    assert!(kem::open::<Kyber768>(&sk, &empty, b"").unwrap().is_empty());
+}
+
+#[test]
+fn open_rejects_wrong_aad_key_or_tampering() {
+    let (pk, sk) = Kyber768::keypair().unwrap();
+    let (_, other_sk) = Kyber768::keypair().unwrap();
+    let env = kem::seal::<Kyber768>(&pk, b"blob", b"aad").unwrap();
+
+    assert!(matches!(
+        kem::open::<Kyber768>(&sk, &env, b"other"),
+        Err(OqsError::VerifyFail)
+    ));
+    assert!(kem::open::<Kyber768>(&other_sk, &env, b"aad").is_err());
+
+    // Every byte after the algorithm name is covered by the KDF or the tag.
+    let name_end = 6 + Kyber768::NAME.len();
+    for i in [name_end, name_end + 2, name_end + 40, env.len() - 1] {
+        let mut bad = env.clone();
+        bad[i] ^= 0x01;
+        assert!(
+            kem::open::<Kyber768>(&sk, &bad, b"aad").is_err(),
+            "byte {}",
+            i
+        );
+    }
+}
+
+#[test]
+fn open_rejects_other_algorithms_and_versions() {
+    let (pk, sk) = Kyber768::keypair().unwrap();
+    let env = kem::seal::<Kyber768>(&pk, b"blob", b"").unwrap();
+
+    let mut renamed = env.clone();
+    renamed[6 + Kyber768::NAME.len() - 1] ^= 0x01;
+    assert!(matches!(
+        kem::open::<Kyber768>(&sk, &renamed, b""),
+        Err(OqsError::InvalidEncoding(_))
+    ));
+
+    let mut v2 = env.clone();
+    v2[4] = 2;
+    assert!(matches!(
+        kem::open::<Kyber768>(&sk, &v2, b""),
+        Err(OqsError::InvalidEncoding(_))
+    ));
+
+    assert!(kem::open::<Kyber768>(&sk, &env[..env.len() / 2], b"").is_err());
+}
@@ -0,0 +1,110 @@
+#![cfg(all(feature = "secure-memory", unix))]
+
+use oqs_safe::kem::{Kem, Kyber768};
+use oqs_safe::sig::{Dilithium2, SignatureScheme};
+use zeroize::Zeroize;
+
+#[test]
+fn backend_reports_secure_memory() {
+    assert!(oqs_safe::backend_info().secure_memory);
+}
+
+#[test]
+fn secrets_round_trip_and_clone() {
+    let (pk, sk) = Kyber768::keypair().unwrap();
+    assert_eq!(sk.len(), Kyber768::SECRET_KEY_LEN);
+    let sk2 = sk.clone();
+    assert_ne!(sk.as_bytes().as_ptr(), sk2.as_bytes().as_ptr());
+    assert_eq!(sk.as_bytes(), sk2.as_bytes());
+
+    let (ct, ss) = Kyber768::encapsulate(&pk).unwrap();
+    let ss2 = Kyber768::decapsulate(&ct, &sk2).unwrap();
+    assert_eq!(ss.as_bytes(), ss2.as_bytes());
+
+    let (pk, sk) = Dilithium2::keypair().unwrap();
+    let sig = Dilithium2::sign(&sk, b"msg").unwrap();
+    Dilithium2::verify(&pk, b"msg", &sig).unwrap();
+}
+
+#[test]
+fn zeroize_empties_the_key() {
+    let (_, mut sk) = Kyber768::keypair().unwrap();
+    sk.zeroize();
+    assert!(sk.is_empty());
+}
+
+#[test]
+fn many_allocations_are_released() {
+    // Each secret holds its own guard-paged mapping; leaking them would
+    // exceed `vm.max_map_count` (65530 by default) before this finishes.
+    for _ in 0..12_000 {
+        let (_, sk) = Kyber768::keypair().unwrap();
+        drop(sk.clone());
+    }
+}
+
+/// Address range of an smaps entry.
+#[cfg(target_os = "linux")]
+fn range(entry: &str) -> (usize, usize) {
+    let (lo, hi) = entry
+        .split_whitespace()
+        .next()
+        .unwrap()
+        .split_once('-')
+        .unwrap();
+    (
+        usize::from_str_radix(lo, 16).unwrap(),
+        usize::from_str_radix(hi, 16).unwrap(),
+    )
+}
+
+/// The `/proc/self/smaps` entry covering `addr`, and the lines of the
+/// mappings right before and after it.
+#[cfg(target_os = "linux")]
+fn smaps_around(addr: usize) -> (String, String, String) {
+    let smaps = std::fs::read_to_string("/proc/self/smaps").unwrap();
+    let mut entries: Vec<String> = Vec::new();
+    for line in smaps.lines() {
+        let is_header = line
+            .split_whitespace()
+            .next()
+            .is_some_and(|f| f.contains('-') && !f.ends_with(':'));
+        if is_header || entries.is_empty() {
+            entries.push(String::new());
+        }
+        let last = entries.last_mut().unwrap();
+        last.push_str(line);
+        last.push('\n');
+    }
+    let i = entries
+        .iter()
+        .position(|e| {
+            let (lo, hi) = range(e);
+            (lo..hi).contains(&addr)
+        })
+        .unwrap();
+    (
+        entries[i - 1].clone(),
+        entries[i].clone(),
+        entries[i + 1].clone(),
+    )
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn secret_pages_are_guarded_and_not_dumped() {
+    let (_, sk) = Kyber768::keypair().unwrap();
+    let (before, own, after) = smaps_around(sk.as_bytes().as_ptr() as usize);
+
+    assert!(own.split_whitespace().nth(1).unwrap().starts_with("rw"));
+    let flags = own.lines().find(|l| l.starts_with("VmFlags:")).unwrap();
+    assert!(flags.split_whitespace().any(|f| f == "dd"), "{flags}");
+
+    for guard in [before, after] {
+        assert!(guard.split_whitespace().nth(1).unwrap().starts_with("---"));
+    }
+
+    // The key ends exactly at the trailing guard page.
+    let end = sk.as_bytes().as_ptr() as usize + sk.len();
+    assert_eq!(end, range(&own).1);
+}
@@ -0,0 +1,61 @@
+#![cfg(feature = "signature")]
+
+use oqs_safe::sig::{Dilithium2, Signature, SignatureScheme, SigningKey, VerifyingKey};
+use signature::{Keypair, SignatureEncoding, Signer, Verifier};
+
+/// Code written against the RustCrypto traits only.
+fn sign_generic<K>(key: &K, msg: &[u8]) -> (K::VerifyingKey, Signature)
+where
+    K: Signer<Signature> + Keypair,
+{
+    (key.verifying_key(), key.sign(msg))
+}
+
+#[test]
+fn signer_verifier_roundtrip() {
+    let key = SigningKey::<Dilithium2>::generate().unwrap();
+    let (vk, sig) = sign_generic(&key, b"payload");
+    assert_eq!(&vk, key.verifying_key());
+    assert_eq!(sig.len(), Dilithium2::SIGNATURE_LEN);
+    vk.verify(b"payload", &sig).unwrap();
+    assert!(vk
+        .verify(
+            b"payload",
+            &Signature::try_from(&sig.to_bytes()[1..]).unwrap()
+        )
+        .is_err());
+}
+
+#[test]
+fn encodings_roundtrip() {
+    let key = SigningKey::<Dilithium2>::generate().unwrap();
+    let sig = key.try_sign(b"payload").unwrap();
+    let bytes: Vec<u8> = sig.to_bytes();
+    let back = Signature::try_from(bytes.as_slice()).unwrap();
+    key.verifying_key().verify(b"payload", &back).unwrap();
+    assert!(Signature::try_from(&[][..]).is_err());
+
+    let vk = VerifyingKey::<Dilithium2>::try_from(key.verifying_key().as_ref()).unwrap();
+    assert_eq!(&vk, key.verifying_key());
+    assert!(VerifyingKey::<Dilithium2>::try_from(&vk.as_ref()[1..]).is_err());
+}
+
+#[test]
+fn from_keypair_checks_lengths() {
+    let (pk, sk) = Dilithium2::keypair().unwrap();
+    assert!(SigningKey::<Dilithium2>::from_keypair(pk.clone(), sk.clone()).is_ok());
+    assert!(VerifyingKey::<Dilithium2>::try_from(sk.as_bytes()).is_err());
+    assert!(!format!(
+        "{:?}",
+        SigningKey::<Dilithium2>::from_keypair(pk, sk).unwrap()
+    )
+    .contains("secret"));
+}
+
+#[cfg(feature = "liboqs")]
+#[test]
+fn verifier_rejects_other_messages() {
+    let key = SigningKey::<Dilithium2>::generate().unwrap();
+    let sig = key.sign(b"payload");
+    assert!(key.verifying_key().verify(b"other", &sig).is_err());
+}
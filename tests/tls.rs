@@ -0,0 +1,94 @@
+#![cfg(feature = "tls")]
+
+use oqs_safe::tls::{client_key_share, client_shared_secret, server_key_share, NamedGroup};
+use oqs_safe::OqsError;
+
+const GROUPS: [NamedGroup; 3] = [
+    NamedGroup::X25519MlKem768,
+    NamedGroup::SecP256r1MlKem768,
+    NamedGroup::MlKem1024,
+];
+
+#[test]
+fn key_share_roundtrip_all_groups() {
+    for group in GROUPS {
+        let (client_share, secret) = client_key_share(group).unwrap();
+        assert_eq!(
+            client_share.len(),
+            group.client_share_len(),
+            "{}",
+            group.name()
+        );
+
+        let (server_share, ss_server) = server_key_share(group, &client_share).unwrap();
+        assert_eq!(
+            server_share.len(),
+            group.server_share_len(),
+            "{}",
+            group.name()
+        );
+
+        let ss_client = client_shared_secret(secret, &server_share).unwrap();
+        assert_eq!(
+            ss_client.as_bytes(),
+            ss_server.as_bytes(),
+            "{}",
+            group.name()
+        );
+        assert_eq!(ss_client.len(), group.shared_secret_len());
+    }
+}
+
+#[test]
+fn wire_sizes_and_codepoints() {
+    let g = NamedGroup::X25519MlKem768;
+    assert_eq!(
+        (g.codepoint(), g.client_share_len(), g.server_share_len()),
+        (0x11EC, 1216, 1120)
+    );
+    let g = NamedGroup::SecP256r1MlKem768;
+    assert_eq!(
+        (g.codepoint(), g.client_share_len(), g.server_share_len()),
+        (0x11EB, 1249, 1153)
+    );
+    let g = NamedGroup::MlKem1024;
+    assert_eq!(
+        (g.codepoint(), g.client_share_len(), g.server_share_len()),
+        (0x0202, 1568, 1568)
+    );
+
+    for group in GROUPS {
+        assert_eq!(NamedGroup::from_codepoint(group.codepoint()), Some(group));
+    }
+    assert_eq!(NamedGroup::from_codepoint(0x001D), None);
+}
+
+#[test]
+fn p256_point_leads_its_hybrid_share() {
+    let group = NamedGroup::SecP256r1MlKem768;
+    let (client_share, _) = client_key_share(group).unwrap();
+    assert_eq!(client_share[0], 0x04);
+    let (server_share, _) = server_key_share(group, &client_share).unwrap();
+    assert_eq!(server_share[0], 0x04);
+}
+
+#[test]
+fn rejects_bad_shares() {
+    for group in GROUPS {
+        let (client_share, _) = client_key_share(group).unwrap();
+        assert!(matches!(
+            server_key_share(group, &client_share[1..]),
+            Err(OqsError::InvalidLength)
+        ));
+    }
+
+    // An all-zero X25519 point yields an all-zero secret, which TLS must reject.
+    let group = NamedGroup::X25519MlKem768;
+    let (mut client_share, _) = client_key_share(group).unwrap();
+    let n = client_share.len();
+    client_share[n - 32..].fill(0);
+    assert!(matches!(
+        server_key_share(group, &client_share),
+        Err(OqsError::InvalidEncoding(_))
+    ));
+}
@@ -0,0 +1,290 @@
+//! Wycheproof-style negative tests for ML-DSA verification: signatures with
+//! a valid length but invalid contents, plus length and context edge cases.
+//! Each case asserts the exact `OqsError` variant.
+//!
+//! liboqs vendors Wycheproof vectors for ML-KEM only
+//! (`liboqs/tests/Wycheproof_Vectors/mlkem_test`), so the cases below are
+//! built in. The ignored `ml_dsa_44_wycheproof_verify` test replays upstream
+//! `mldsa_44_verify_test.json` from a Wycheproof `testvectors_v1` checkout in
+//! `WYCHEPROOF_DIR`.
+//!
+//! The mock backend only checks signature lengths, so everything semantic
+//! needs the liboqs backend.
+
+#![cfg(feature = "testing")]
+
+use oqs_safe::sig::{Dilithium2, Signature, SignatureScheme};
+use oqs_safe::OqsError;
+
+#[test]
+fn overlong_signature_is_invalid_length() {
+    let (pk, sk) = Dilithium2::keypair().unwrap();
+    let mut bytes = Dilithium2::sign(&sk, b"msg").unwrap().as_bytes().to_vec();
+    bytes.push(0);
+    let sig = Signature::from_bytes_unchecked(bytes);
+    assert!(matches!(
+        Dilithium2::verify(&pk, b"msg", &sig),
+        Err(OqsError::InvalidLength)
+    ));
+}
+
+#[cfg(feature = "liboqs")]
+mod liboqs_cases {
+    use super::*;
+    use oqs_safe::sig::PublicKey;
+
+    /// The outcome a case must produce.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Expect {
+        Valid,
+        VerifyFail,
+        InvalidLength,
+    }
+
+    fn outcome(result: Result<(), OqsError>) -> Expect {
+        match result {
+            Ok(()) => Expect::Valid,
+            Err(OqsError::VerifyFail) => Expect::VerifyFail,
+            Err(OqsError::InvalidLength) => Expect::InvalidLength,
+            Err(e) => panic!("unexpected error: {e:?}"),
+        }
+    }
+
+    /// FIPS 204 signature layout: `c~ || z || h`, with the hint `h` encoded
+    /// as `omega` position bytes followed by `k` cumulative counts.
+    struct MlDsaLayout {
+        c_tilde: usize,
+        omega: usize,
+        k: usize,
+    }
+
+    impl MlDsaLayout {
+        fn hint_start<S: SignatureScheme>(&self) -> usize {
+            S::SIGNATURE_LEN - self.omega - self.k
+        }
+    }
+
+    const ML_DSA_44: MlDsaLayout = MlDsaLayout {
+        c_tilde: 32,
+        omega: 80,
+        k: 4,
+    };
+
+    type Mutation = fn(&MlDsaLayout, usize, &mut Vec<u8>);
+
+    /// `(comment, mutation of a valid signature, expected outcome)`; the
+    /// `usize` is the offset of the hint section.
+    const SIGNATURE_CASES: &[(&str, Mutation, Expect)] = &[
+        ("unmodified", |_, _, _| {}, Expect::Valid),
+        (
+            "flipped bit in c~",
+            |_, _, s| s[0] ^= 0x01,
+            Expect::VerifyFail,
+        ),
+        (
+            "flipped bit in z",
+            |l, _, s| s[l.c_tilde + 100] ^= 0x01,
+            Expect::VerifyFail,
+        ),
+        (
+            // A packed value of 0 decodes to gamma1, outside the norm bound.
+            "first z coefficient out of range",
+            |l, _, s| {
+                s[l.c_tilde] = 0;
+                s[l.c_tilde + 1] = 0;
+                s[l.c_tilde + 2] &= 0xfc;
+            },
+            Expect::VerifyFail,
+        ),
+        (
+            "flipped bit in hint",
+            |_, h, s| s[h] ^= 0x01,
+            Expect::VerifyFail,
+        ),
+        (
+            "hint count exceeds omega",
+            |l, h, s| s[h + l.omega] = l.omega as u8 + 1,
+            Expect::VerifyFail,
+        ),
+        (
+            "hint counts decrease",
+            |l, h, s| {
+                s[h..].fill(0);
+                s[h] = 1;
+                s[h + l.omega] = 1;
+            },
+            Expect::VerifyFail,
+        ),
+        (
+            "hint positions not increasing",
+            |l, h, s| {
+                s[h..].fill(0);
+                s[h] = 5;
+                s[h + 1] = 5;
+                s[h + l.omega..].fill(2);
+            },
+            Expect::VerifyFail,
+        ),
+        (
+            "nonzero unused hint position",
+            |_, h, s| {
+                s[h..].fill(0);
+                s[h] = 1;
+            },
+            Expect::VerifyFail,
+        ),
+        ("all zero", |_, _, s| s.fill(0), Expect::VerifyFail),
+        ("all ones", |_, _, s| s.fill(0xff), Expect::VerifyFail),
+        // `SIGNATURE_LEN` is a maximum; shorter inputs reach liboqs, which
+        // rejects them as signatures.
+        (
+            "truncated by one byte",
+            |_, _, s| s.truncate(s.len() - 1),
+            Expect::VerifyFail,
+        ),
+        ("empty", |_, _, s| s.clear(), Expect::VerifyFail),
+        (
+            "one byte appended",
+            |_, _, s| s.push(0),
+            Expect::InvalidLength,
+        ),
+    ];
+
+    fn check_signature_cases<S: SignatureScheme>(layout: &MlDsaLayout) {
+        let (pk, sk) = S::keypair().unwrap();
+        let msg = b"Wycheproof-style ML-DSA test message";
+        let valid = S::sign(&sk, msg).unwrap();
+        let hint = layout.hint_start::<S>();
+
+        for (comment, mutate, expected) in SIGNATURE_CASES {
+            let mut bytes = valid.as_bytes().to_vec();
+            mutate(layout, hint, &mut bytes);
+            let sig = Signature::from_bytes_unchecked(bytes);
+            let got = outcome(S::verify(&pk, msg, &sig));
+            assert_eq!(got, *expected, "{}: {comment}", S::NAME);
+        }
+    }
+
+    fn check_message_and_key_cases<S: SignatureScheme>() {
+        let (pk, sk) = S::keypair().unwrap();
+        let msg = b"message".to_vec();
+        let sig = S::sign(&sk, &msg).unwrap();
+
+        let mut flipped = msg.clone();
+        flipped[0] ^= 0x01;
+        let mut extended = msg.clone();
+        extended.push(0);
+        let (other_pk, _) = S::keypair().unwrap();
+        let short_pk = PublicKey::from_bytes_unchecked(pk.as_bytes()[1..].to_vec());
+
+        let cases: [(&str, &PublicKey, &[u8], Expect); 5] = [
+            ("flipped bit in message", &pk, &flipped, Expect::VerifyFail),
+            (
+                "byte appended to message",
+                &pk,
+                &extended,
+                Expect::VerifyFail,
+            ),
+            ("empty message", &pk, b"", Expect::VerifyFail),
+            ("other public key", &other_pk, &msg, Expect::VerifyFail),
+            (
+                "truncated public key",
+                &short_pk,
+                &msg,
+                Expect::InvalidLength,
+            ),
+        ];
+        for (comment, pk, msg, expected) in cases {
+            let got = outcome(S::verify(pk, msg, &sig));
+            assert_eq!(got, expected, "{}: {comment}", S::NAME);
+        }
+
+        // An empty message is an ordinary input.
+        let sig = S::sign(&sk, b"").unwrap();
+        assert_eq!(outcome(S::verify(&pk, b"", &sig)), Expect::Valid);
+    }
+
+    #[test]
+    fn ml_dsa_44_rejects_malformed_signatures() {
+        check_signature_cases::<Dilithium2>(&ML_DSA_44);
+    }
+
+    #[test]
+    fn ml_dsa_44_rejects_wrong_message_and_key() {
+        check_message_and_key_cases::<Dilithium2>();
+    }
+
+    #[test]
+    fn ml_dsa_44_context_strings() {
+        let (pk, sk) = Dilithium2::keypair().unwrap();
+        let msg = b"msg";
+        let verify = |sig: &Signature, ctx: &[u8]| {
+            outcome(Dilithium2::verify_with_context(&pk, msg, sig, ctx))
+        };
+
+        let sig = Dilithium2::sign_with_context(&sk, msg, b"ctx").unwrap();
+        assert_eq!(verify(&sig, b"ctx"), Expect::Valid);
+        assert_eq!(verify(&sig, b""), Expect::VerifyFail);
+        assert_eq!(verify(&sig, b"ctX"), Expect::VerifyFail);
+        assert_eq!(
+            outcome(Dilithium2::verify(&pk, msg, &sig)),
+            Expect::VerifyFail
+        );
+
+        // Plain `sign` is ML-DSA with the empty context.
+        let sig = Dilithium2::sign(&sk, msg).unwrap();
+        assert_eq!(verify(&sig, b""), Expect::Valid);
+
+        let max = [0x5a; 255];
+        let sig = Dilithium2::sign_with_context(&sk, msg, &max).unwrap();
+        assert_eq!(verify(&sig, &max), Expect::Valid);
+        assert_eq!(verify(&sig, &[0x5a; 256]), Expect::InvalidLength);
+        assert!(matches!(
+            Dilithium2::sign_with_context(&sk, msg, &[0; 256]),
+            Err(OqsError::InvalidLength)
+        ));
+    }
+
+    /// Upstream `mldsa_44_verify_test.json` from `WYCHEPROOF_DIR`.
+    #[test]
+    #[ignore = "set WYCHEPROOF_DIR to a Wycheproof testvectors_v1 checkout"]
+    fn ml_dsa_44_wycheproof_verify() {
+        let dir = std::env::var_os("WYCHEPROOF_DIR").expect("WYCHEPROOF_DIR not set");
+        let path = std::path::Path::new(&dir).join("mldsa_44_verify_test.json");
+        let text =
+            std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {e}", path.display()));
+        let json: serde_json::Value = serde_json::from_str(&text).expect("parse Wycheproof JSON");
+        let hex = |v: &serde_json::Value| hex::decode(v.as_str().unwrap_or("")).unwrap();
+
+        let mut cases = 0;
+        for group in json["testGroups"].as_array().expect("testGroups") {
+            let pk = PublicKey::from_bytes_unchecked(hex(&group["publicKey"]));
+            for test in group["tests"].as_array().expect("tests") {
+                let id = &test["tcId"];
+                let sig = Signature::from_bytes_unchecked(hex(&test["sig"]));
+                let ctx = hex(&test["ctx"]);
+                let expected = match test["result"].as_str() {
+                    Some("valid") => Expect::Valid,
+                    Some("invalid")
+                        if pk.len() != Dilithium2::PUBLIC_KEY_LEN
+                            || sig.len() > Dilithium2::SIGNATURE_LEN
+                            || ctx.len() > 255 =>
+                    {
+                        Expect::InvalidLength
+                    }
+                    Some("invalid") => Expect::VerifyFail,
+                    other => panic!("tcId {id}: unexpected result {other:?}"),
+                };
+                let got = outcome(Dilithium2::verify_with_context(
+                    &pk,
+                    &hex(&test["msg"]),
+                    &sig,
+                    &ctx,
+                ));
+                assert_eq!(got, expected, "tcId {id}: {}", test["comment"]);
+                cases += 1;
+            }
+        }
+        assert!(cases > 0, "no test cases in {}", path.display());
+    }
+}